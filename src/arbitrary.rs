@@ -0,0 +1,49 @@
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use tagged_vec::TaggedVec;
+
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge},
+    index::NodeIndex,
+    io::gfa1::{PlainGfaEdgeData, PlainGfaNodeData},
+};
+
+#[cfg(test)]
+mod tests;
+
+const MAX_NODE_COUNT: usize = 256;
+const MAX_EDGE_COUNT: usize = 1024;
+const MAX_SEQUENCE_LENGTH: usize = 32;
+
+/// Generates graphs that are always valid inputs to [`BidirectedAdjacencyArray::new`].
+///
+/// Nodes have unique names and sequences over `ACGT`, and all edges reference existing nodes.
+/// The sizes are bounded such that all indices fit into `u16`.
+impl<'a> Arbitrary<'a> for BidirectedAdjacencyArray<u16, PlainGfaNodeData, PlainGfaEdgeData> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let node_count = u.int_in_range(0..=MAX_NODE_COUNT)?;
+        let mut nodes = TaggedVec::<NodeIndex<u16>, _>::with_capacity(node_count);
+        for node in 0..node_count {
+            let sequence_length = u.int_in_range(0..=MAX_SEQUENCE_LENGTH)?;
+            let sequence = (0..sequence_length)
+                .map(|_| u.choose(&['A', 'C', 'G', 'T']).copied())
+                .collect::<Result<String>>()?;
+            nodes.push(PlainGfaNodeData::new(format!("N{node}"), sequence));
+        }
+
+        let mut edges = TaggedVec::new();
+        if node_count > 0 {
+            let edge_count = u.int_in_range(0..=MAX_EDGE_COUNT)?;
+            for _ in 0..edge_count {
+                edges.push(BidirectedEdge {
+                    from: NodeIndex::from_usize(u.choose_index(node_count)?),
+                    from_forward: u.arbitrary()?,
+                    to: NodeIndex::from_usize(u.choose_index(node_count)?),
+                    to_forward: u.arbitrary()?,
+                    data: PlainGfaEdgeData::new(u.arbitrary()?),
+                });
+            }
+        }
+
+        Ok(Self::new(nodes, edges))
+    }
+}
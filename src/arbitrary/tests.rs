@@ -0,0 +1,33 @@
+use ::arbitrary::{Arbitrary, Unstructured};
+
+use crate::{
+    graph::BidirectedAdjacencyArray,
+    io::gfa1::{PlainGfaEdgeData, PlainGfaNodeData, read_gfa1, write_gfa1},
+};
+
+#[test]
+fn test_arbitrary_graphs_are_valid() {
+    for seed in 0..100u32 {
+        let bytes = (0..4096u32)
+            .map(|index| (index.wrapping_mul(2_654_435_761).wrapping_add(seed * 97) >> 7) as u8)
+            .collect::<Vec<_>>();
+        let mut unstructured = Unstructured::new(&bytes);
+        let graph = BidirectedAdjacencyArray::<u16, PlainGfaNodeData, PlainGfaEdgeData>::arbitrary(
+            &mut unstructured,
+        )
+        .unwrap();
+
+        let mut buffer = Vec::new();
+        write_gfa1(&graph, &mut buffer).unwrap();
+        let actual_graph = read_gfa1::<u16>(&mut buffer.as_slice()).unwrap();
+        graph.expect_equal(&actual_graph);
+    }
+
+    // Running out of data still produces a valid graph.
+    let mut unstructured = Unstructured::new(&[]);
+    let graph = BidirectedAdjacencyArray::<u16, PlainGfaNodeData, PlainGfaEdgeData>::arbitrary(
+        &mut unstructured,
+    )
+    .unwrap();
+    assert_eq!(graph.node_count(), 0);
+}
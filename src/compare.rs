@@ -1,3 +1,16 @@
+use alloc::{
+    borrow::Cow,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    convert::Infallible,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::ControlFlow,
+};
+
 use thiserror::Error;
 
 use crate::{
@@ -5,6 +18,9 @@ use crate::{
     index::{EdgeIndex, GraphIndexInteger, NodeIndex},
 };
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Error)]
 pub enum GraphComparisonError<IndexType> {
     #[error("the number of nodes in the graphs differ")]
@@ -23,24 +39,153 @@ pub enum GraphComparisonError<IndexType> {
     EdgeEndpointMismatch(EdgeIndex<IndexType>),
 }
 
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GraphDiffError {
+    #[error("the node name '{0}' occurs more than once in the first graph")]
+    DuplicateNameInSelf(String),
+
+    #[error("the node name '{0}' occurs more than once in the second graph")]
+    DuplicateNameInOther(String),
+}
+
+/// The differences between two graphs whose nodes are matched by name, as computed by [`BidirectedAdjacencyArray::diff`].
+///
+/// All lists are sorted, and the [`Display`](fmt::Display) implementation prints a readable summary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphDiff {
+    /// The names of the nodes that exist only in the first graph.
+    pub nodes_only_in_self: Vec<String>,
+    /// The names of the nodes that exist only in the second graph.
+    pub nodes_only_in_other: Vec<String>,
+    /// The names of the nodes that exist in both graphs, but with different data.
+    pub nodes_with_different_data: Vec<String>,
+    /// The edges that exist only in the first graph, once for each missing parallel edge.
+    pub edges_only_in_self: Vec<NamedEdge>,
+    /// The edges that exist only in the second graph, once for each missing parallel edge.
+    pub edges_only_in_other: Vec<NamedEdge>,
+    /// The edges that exist in both graphs, but with different data.
+    pub edges_with_different_data: Vec<NamedEdge>,
+}
+
+/// An edge whose endpoints are identified by the names of their nodes, in canonical orientation.
+///
+/// Like [`BidirectedEdge::canonical`](crate::graph::BidirectedEdge::canonical), an edge and its reverse complement
+/// are represented by the orientation whose `(from, to)` tuple is smaller,
+/// where the endpoints are ordered by their name and then forward before reverse.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NamedEdge {
+    pub from: String,
+    pub from_forward: bool,
+    pub to: String,
+    pub to_forward: bool,
+}
+
+/// Graphs are equal if [`BidirectedAdjacencyArray::compare`] finds no difference.
+///
+/// This means that the nodes and edges must be identical including their indices.
+/// Isomorphic graphs with different indices are not equal.
+impl<IndexType: GraphIndexInteger, NodeData: Eq, EdgeData: Eq> PartialEq
+    for BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.compare(other).is_ok()
+    }
+}
+
+impl<IndexType: GraphIndexInteger, NodeData: Eq, EdgeData: Eq> Eq
+    for BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+}
+
+/// Hashes the node and edge counts, the node data in order, and each edge in [canonical orientation](crate::graph::BidirectedEdge::canonical) in order.
+///
+/// This is consistent with [`PartialEq`], so graphs can be used as keys of hash maps.
+/// Since the hash depends on the indices of the nodes and edges, graphs with the same edges constructed in a different order hash differently.
+/// Call [`BidirectedAdjacencyArray::canonicalize`] before hashing to make the hash independent of the edge order and orientations.
+impl<IndexType: GraphIndexInteger, NodeData: Hash, EdgeData: Hash> Hash
+    for BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    fn hash<State: Hasher>(&self, state: &mut State) {
+        self.node_count().hash(state);
+        self.edge_count().hash(state);
+        for (_, node_data) in self.iter_node_data() {
+            node_data.hash(state);
+        }
+        for edge in self.iter_edges() {
+            self.bidirected_edge(edge).canonical().hash(state);
+        }
+    }
+}
+
 impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
     BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
 {
     /// Compares this graph to another graph.
     ///
     /// The comparison returns `Ok` if all nodes and edges are identical in both graphs.
-    /// Otherwise, it returns an `Err` describing the differences.
+    /// Otherwise, it returns an `Err` describing the first difference found.
     pub fn compare(&self, other: &Self) -> Result<(), GraphComparisonError<IndexType>>
+    where
+        NodeData: Eq,
+        EdgeData: Eq,
+    {
+        match self.compare_with(other, ControlFlow::Break) {
+            ControlFlow::Continue(()) => Ok(()),
+            ControlFlow::Break(error) => Err(error),
+        }
+    }
+
+    /// Compares this graph to another graph, collecting all differences.
+    ///
+    /// Returns an empty vector if all nodes and edges are identical in both graphs.
+    /// If the node or edge counts differ, then only the count mismatches are reported,
+    /// since the individual nodes and edges cannot be compared meaningfully.
+    pub fn compare_all(&self, other: &Self) -> Vec<GraphComparisonError<IndexType>>
+    where
+        NodeData: Eq,
+        EdgeData: Eq,
+    {
+        let mut errors = Vec::new();
+
+        if self.node_count() != other.node_count() {
+            errors.push(GraphComparisonError::NodeCountMismatch);
+        }
+
+        if self.edge_count() != other.edge_count() {
+            errors.push(GraphComparisonError::EdgeCountMismatch);
+        }
+
+        if errors.is_empty() {
+            let ControlFlow::Continue(()) = self.compare_with(other, |error| {
+                errors.push(error);
+                ControlFlow::<Infallible>::Continue(())
+            });
+        }
+
+        errors
+    }
+
+    /// Compares this graph to another graph, reporting each difference to `on_difference`.
+    ///
+    /// The comparison stops as soon as `on_difference` returns [`ControlFlow::Break`].
+    /// Count mismatches always stop the comparison.
+    fn compare_with<Break>(
+        &self,
+        other: &Self,
+        mut on_difference: impl FnMut(GraphComparisonError<IndexType>) -> ControlFlow<Break>,
+    ) -> ControlFlow<Break>
     where
         NodeData: Eq,
         EdgeData: Eq,
     {
         if self.node_count() != other.node_count() {
-            return Err(GraphComparisonError::NodeCountMismatch);
+            on_difference(GraphComparisonError::NodeCountMismatch)?;
+            return ControlFlow::Continue(());
         }
 
         if self.edge_count() != other.edge_count() {
-            return Err(GraphComparisonError::EdgeCountMismatch);
+            on_difference(GraphComparisonError::EdgeCountMismatch)?;
+            return ControlFlow::Continue(());
         }
 
         for node_index in self.iter_nodes() {
@@ -48,7 +193,7 @@ impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
             let other_node_data = other.node_data(node_index);
 
             if self_node_data != other_node_data {
-                return Err(GraphComparisonError::NodeDataMismatch(node_index));
+                on_difference(GraphComparisonError::NodeDataMismatch(node_index))?;
             }
         }
 
@@ -57,15 +202,142 @@ impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
             let other_edge = other.edge(edge_index);
 
             if self_edge.data() != other_edge.data() {
-                return Err(GraphComparisonError::EdgeDataMismatch(edge_index));
+                on_difference(GraphComparisonError::EdgeDataMismatch(edge_index))?;
             }
 
             if self_edge.from() != other_edge.from() || self_edge.to() != other_edge.to() {
-                return Err(GraphComparisonError::EdgeEndpointMismatch(edge_index));
+                on_difference(GraphComparisonError::EdgeEndpointMismatch(edge_index))?;
             }
         }
 
-        Ok(())
+        ControlFlow::Continue(())
+    }
+
+    /// Lists the differences between this graph and another graph, matching their nodes by the names returned by `name_of`.
+    ///
+    /// Unlike [`Self::compare`], this does not depend on the node and edge indices,
+    /// so it can compare the outputs of different tools that order the nodes and edges differently.
+    /// Edges are matched by their endpoints and orientations, where an edge matches its reverse complement.
+    /// Parallel edges are matched as a multiset: first, parallel edges with equal data are matched with each other,
+    /// then the remaining ones are paired up as edges with different data, and any left over exist only in one graph.
+    ///
+    /// Returns an error if a name is not unique within one of the graphs.
+    pub fn diff(
+        &self,
+        other: &Self,
+        name_of: impl Fn(&NodeData) -> Cow<'_, str>,
+    ) -> Result<GraphDiff, GraphDiffError>
+    where
+        NodeData: Eq,
+        EdgeData: Eq,
+    {
+        let mut diff = GraphDiff::default();
+
+        let self_nodes = self
+            .nodes_by_name(&name_of)
+            .map_err(GraphDiffError::DuplicateNameInSelf)?;
+        let other_nodes = other
+            .nodes_by_name(&name_of)
+            .map_err(GraphDiffError::DuplicateNameInOther)?;
+        for (name, &node) in &self_nodes {
+            match other_nodes.get(name) {
+                None => diff.nodes_only_in_self.push(name.clone()),
+                Some(&other_node) => {
+                    if self.node_data(node) != other.node_data(other_node) {
+                        diff.nodes_with_different_data.push(name.clone());
+                    }
+                }
+            }
+        }
+        diff.nodes_only_in_other = other_nodes
+            .into_keys()
+            .filter(|name| !self_nodes.contains_key(name))
+            .collect();
+
+        let self_edges = self.edges_by_name(&name_of);
+        let mut other_edges = other.edges_by_name(&name_of);
+        for (edge, self_data) in self_edges {
+            let mut other_data = other_edges.remove(&edge).unwrap_or_default();
+
+            // Cancel out parallel edges with equal data.
+            let mut remaining_self_data = Vec::new();
+            for data in self_data {
+                if let Some(position) = other_data.iter().position(|other| *other == data) {
+                    other_data.swap_remove(position);
+                } else {
+                    remaining_self_data.push(data);
+                }
+            }
+
+            let different_count = remaining_self_data.len().min(other_data.len());
+            for _ in 0..different_count {
+                diff.edges_with_different_data.push(edge.clone());
+            }
+            for _ in different_count..remaining_self_data.len() {
+                diff.edges_only_in_self.push(edge.clone());
+            }
+            for _ in different_count..other_data.len() {
+                diff.edges_only_in_other.push(edge.clone());
+            }
+        }
+        for (edge, other_data) in other_edges {
+            for _ in 0..other_data.len() {
+                diff.edges_only_in_other.push(edge.clone());
+            }
+        }
+        diff.edges_only_in_other.sort();
+
+        Ok(diff)
+    }
+
+    /// Maps the names of the nodes to their indices, or returns the first name that occurs more than once.
+    fn nodes_by_name(
+        &self,
+        name_of: &impl Fn(&NodeData) -> Cow<'_, str>,
+    ) -> Result<BTreeMap<String, NodeIndex<IndexType>>, String> {
+        let mut nodes = BTreeMap::new();
+        for (node, node_data) in self.iter_node_data() {
+            let name = name_of(node_data).into_owned();
+            if nodes.contains_key(&name) {
+                return Err(name);
+            }
+            nodes.insert(name, node);
+        }
+        Ok(nodes)
+    }
+
+    /// Groups the data of the edges by their [`NamedEdge`].
+    fn edges_by_name(
+        &self,
+        name_of: &impl Fn(&NodeData) -> Cow<'_, str>,
+    ) -> BTreeMap<NamedEdge, Vec<&EdgeData>> {
+        let mut edges = BTreeMap::<_, Vec<_>>::new();
+        for edge in self.iter_edge_views() {
+            let (from, from_forward) = edge.from_bidirected();
+            let (to, to_forward) = edge.to_bidirected();
+            let from = name_of(self.node_data(from)).into_owned();
+            let to = name_of(self.node_data(to)).into_owned();
+            let reverse_complement = NamedEdge {
+                from: to.clone(),
+                from_forward: !to_forward,
+                to: from.clone(),
+                to_forward: !from_forward,
+            };
+            let named_edge = NamedEdge {
+                from,
+                from_forward,
+                to,
+                to_forward,
+            };
+            let named_edge = if reverse_complement.orientation_key() < named_edge.orientation_key()
+            {
+                reverse_complement
+            } else {
+                named_edge
+            };
+            edges.entry(named_edge).or_default().push(edge.data());
+        }
+        edges
     }
 
     #[cfg(test)]
@@ -86,11 +358,80 @@ impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
                 let actual_to = actual_edge.to();
 
                 panic!(
-                    "{error}:\nExpected ({}, {})\nActual   ({}, {})",
-                    expected_from, expected_to, actual_from, actual_to,
+                    "{error}:\nExpected ({expected_from}, {expected_to})\nActual   ({actual_from}, {actual_to})",
                 );
             }
             Err(e) => panic!("Graphs differ: {:?}", e),
         }
     }
 }
+
+impl GraphDiff {
+    /// Returns true if no differences were found.
+    pub fn is_empty(&self) -> bool {
+        self.nodes_only_in_self.is_empty()
+            && self.nodes_only_in_other.is_empty()
+            && self.nodes_with_different_data.is_empty()
+            && self.edges_only_in_self.is_empty()
+            && self.edges_only_in_other.is_empty()
+            && self.edges_with_different_data.is_empty()
+    }
+}
+
+impl fmt::Display for GraphDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "the graphs are equal");
+        }
+
+        let sections = [
+            ("nodes only in the first graph", &self.nodes_only_in_self),
+            ("nodes only in the second graph", &self.nodes_only_in_other),
+            ("nodes with different data", &self.nodes_with_different_data),
+        ]
+        .into_iter()
+        .map(|(title, nodes)| (title, nodes.iter().map(ToString::to_string).collect()))
+        .chain(
+            [
+                ("edges only in the first graph", &self.edges_only_in_self),
+                ("edges only in the second graph", &self.edges_only_in_other),
+                ("edges with different data", &self.edges_with_different_data),
+            ]
+            .into_iter()
+            .map(|(title, edges)| (title, edges.iter().map(ToString::to_string).collect())),
+        )
+        .filter(|(_, items): &(_, Vec<String>)| !items.is_empty());
+
+        for (index, (title, items)) in sections.enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{} {title}:", items.len())?;
+            for item in items {
+                write!(f, "\n    {item}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl NamedEdge {
+    /// The key by which the canonical orientation is chosen.
+    fn orientation_key(&self) -> (&str, bool, &str, bool) {
+        (&self.from, !self.from_forward, &self.to, !self.to_forward)
+    }
+}
+
+impl fmt::Display for NamedEdge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = |forward| if forward { '+' } else { '-' };
+        write!(
+            f,
+            "{}{} -> {}{}",
+            self.from,
+            sign(self.from_forward),
+            self.to,
+            sign(self.to_forward),
+        )
+    }
+}
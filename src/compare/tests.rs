@@ -0,0 +1,186 @@
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    hash::{BuildHasher, RandomState},
+};
+
+use crate::{
+    compare::{GraphComparisonError, GraphDiff, GraphDiffError, NamedEdge},
+    graph::{BidirectedAdjacencyArray, BidirectedEdge},
+    index::DirectedNodeIndex,
+    test_util::{directed, graph},
+};
+
+#[test]
+fn test_compare_all_collects_all_differences() {
+    let a = graph(
+        vec![0, 1, 2],
+        vec![
+            (directed(0, true), directed(1, true), 0),
+            (directed(1, true), directed(2, true), 1),
+        ],
+    );
+    let b = graph(
+        vec![0, 5, 6],
+        vec![
+            (directed(0, true), directed(1, true), 7),
+            (directed(1, true), directed(2, false), 8),
+        ],
+    );
+
+    assert!(matches!(
+        a.compare(&b),
+        Err(GraphComparisonError::NodeDataMismatch(node)) if node == 1.into()
+    ));
+
+    let errors = a.compare_all(&b);
+    assert_eq!(errors.len(), 5, "{errors:?}");
+    assert!(matches!(errors[0], GraphComparisonError::NodeDataMismatch(node) if node == 1.into()));
+    assert!(matches!(errors[1], GraphComparisonError::NodeDataMismatch(node) if node == 2.into()));
+    assert!(matches!(errors[2], GraphComparisonError::EdgeDataMismatch(edge) if edge == 0.into()));
+    assert!(matches!(errors[3], GraphComparisonError::EdgeDataMismatch(edge) if edge == 1.into()));
+    assert!(
+        matches!(errors[4], GraphComparisonError::EdgeEndpointMismatch(edge) if edge == 1.into())
+    );
+
+    assert!(a.compare_all(&a).is_empty());
+}
+
+#[test]
+fn test_compare_all_count_mismatch() {
+    let a = graph(vec![0, 1], vec![(directed(0, true), directed(1, true), 0)]);
+    let b = graph(vec![0, 1, 2], Vec::new());
+
+    let errors = a.compare_all(&b);
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], GraphComparisonError::NodeCountMismatch));
+    assert!(matches!(errors[1], GraphComparisonError::EdgeCountMismatch));
+}
+
+#[test]
+fn test_partial_eq() {
+    let a = graph(vec![0, 1], vec![(directed(0, true), directed(1, false), 0)]);
+    let b = graph(vec![0, 1], vec![(directed(0, true), directed(1, false), 0)]);
+    // The same edge in reverse-complemental orientation, which has different endpoints.
+    let c = graph(vec![0, 1], vec![(directed(1, true), directed(0, false), 0)]);
+    let d = graph(vec![0, 1], vec![(directed(0, true), directed(1, false), 1)]);
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_ne!(a, d);
+}
+
+#[test]
+fn test_hash() {
+    let hash_builder = RandomState::new();
+    let mut a = graph(
+        vec![0, 1, 2],
+        vec![
+            (directed(0, true), directed(1, true), 0),
+            (directed(2, false), directed(1, false), 1),
+        ],
+    );
+    let mut b = graph(
+        vec![0, 1, 2],
+        vec![
+            (directed(1, true), directed(2, true), 1),
+            (directed(0, true), directed(1, true), 0),
+        ],
+    );
+    assert_ne!(a, b);
+
+    a.canonicalize();
+    b.canonicalize();
+    assert_eq!(a, b);
+    assert_eq!(hash_builder.hash_one(&a), hash_builder.hash_one(&b));
+
+    let c = graph(
+        vec![0, 1, 2],
+        vec![
+            (directed(0, true), directed(1, true), 0),
+            (directed(1, true), directed(2, true), 2),
+        ],
+    );
+    let graphs = HashSet::from([a, b, c]);
+    assert_eq!(graphs.len(), 2);
+}
+
+#[test]
+fn test_diff() {
+    let named_graph =
+        |nodes: Vec<(&'static str, u8)>,
+         edges: Vec<(DirectedNodeIndex<u8>, DirectedNodeIndex<u8>, u8)>| {
+            BidirectedAdjacencyArray::<u8, _, _>::new(
+                nodes.into(),
+                edges
+                    .into_iter()
+                    .map(|(from, to, data)| BidirectedEdge::new(from, to, data))
+                    .collect(),
+            )
+        };
+    let a = named_graph(
+        vec![("N0", 0), ("N1", 1), ("N2", 2), ("N3", 3)],
+        vec![
+            (directed(0, true), directed(1, true), 0),
+            (directed(1, true), directed(2, false), 1),
+            (directed(2, true), directed(2, true), 2),
+            (directed(2, true), directed(2, true), 3),
+            (directed(0, false), directed(3, true), 4),
+        ],
+    );
+    // The same graph with permuted nodes, reversed edges and some changes.
+    let b = named_graph(
+        vec![("N2", 2), ("N4", 4), ("N1", 5), ("N0", 0)],
+        vec![
+            (directed(2, true), directed(0, false), 1),
+            (directed(0, true), directed(0, true), 3),
+            (directed(3, true), directed(2, true), 7),
+            (directed(1, true), directed(3, false), 4),
+        ],
+    );
+    fn name_of<'a>((name, _): &'a (&str, u8)) -> Cow<'a, str> {
+        Cow::Borrowed(name)
+    }
+
+    let edge = |from: &str, from_forward, to: &str, to_forward| NamedEdge {
+        from: from.to_string(),
+        from_forward,
+        to: to.to_string(),
+        to_forward,
+    };
+    let diff = a.diff(&b, name_of).unwrap();
+    assert_eq!(
+        diff,
+        GraphDiff {
+            nodes_only_in_self: vec!["N3".to_string()],
+            nodes_only_in_other: vec!["N4".to_string()],
+            nodes_with_different_data: vec!["N1".to_string()],
+            edges_only_in_self: vec![edge("N0", false, "N3", true), edge("N2", true, "N2", true)],
+            edges_only_in_other: vec![edge("N0", true, "N4", false)],
+            edges_with_different_data: vec![edge("N0", true, "N1", true)],
+        }
+    );
+    assert_eq!(
+        diff.to_string(),
+        "1 nodes only in the first graph:\n    N3\n\
+         1 nodes only in the second graph:\n    N4\n\
+         1 nodes with different data:\n    N1\n\
+         2 edges only in the first graph:\n    N0- -> N3+\n    N2+ -> N2+\n\
+         1 edges only in the second graph:\n    N0+ -> N4-\n\
+         1 edges with different data:\n    N0+ -> N1+"
+    );
+
+    let diff = b.diff(&b, name_of).unwrap();
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "the graphs are equal");
+
+    let duplicate = named_graph(vec![("N0", 0), ("N1", 1), ("N0", 2)], Vec::new());
+    assert_eq!(
+        a.diff(&duplicate, name_of),
+        Err(GraphDiffError::DuplicateNameInOther("N0".to_string()))
+    );
+    assert_eq!(
+        duplicate.diff(&a, name_of),
+        Err(GraphDiffError::DuplicateNameInSelf("N0".to_string()))
+    );
+}
@@ -0,0 +1,41 @@
+//! Traits for accessing the sequence data stored at nodes and edges.
+//!
+//! These are used by the GFA readers and writers in [`io`](crate::io), but also by graph algorithms that work with sequences and overlaps.
+
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+pub trait GfaNodeData {
+    fn name(&'_ self) -> Cow<'_, str>;
+
+    /// Returns the sequence of the node, or `None` if it is not stored.
+    ///
+    /// A missing sequence is written as `*` in GFA.
+    fn sequence(&'_ self) -> Option<Cow<'_, str>>;
+
+    /// Returns the length of the sequence of the node, if known.
+    ///
+    /// This may be known even if the sequence itself is not stored.
+    fn sequence_length(&self) -> Option<usize> {
+        self.sequence().map(|sequence| sequence.len())
+    }
+
+    /// Returns the optional tags of the node, which are written after the sequence, e.g. `KC:i:5`.
+    fn tags(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+pub trait GfaEdgeData {
+    /// Returns the length of the overlap, which is zero if the overlap is [not specified](Self::is_overlap_specified).
+    fn overlap(&self) -> u16;
+
+    /// Returns false if the overlap is not specified, i.e. given as `*` in GFA.
+    fn is_overlap_specified(&self) -> bool {
+        true
+    }
+
+    /// Returns the CIGAR string of the overlap alignment, if stored.
+    fn cigar(&'_ self) -> Option<Cow<'_, str>> {
+        None
+    }
+}
@@ -1,19 +1,45 @@
-use std::iter;
+use alloc::vec::Vec;
+use core::{
+    hash::{Hash, Hasher},
+    iter,
+};
 
 use tagged_vec::TaggedVec;
 
+#[cfg(feature = "std")]
+use crate::io::gfa1::PlainGfaEdgeData;
 use crate::{
+    data::GfaEdgeData,
     index::{
         DirectedEdgeIndex, DirectedNodeIndex, EdgeIndex, GraphIndexInteger, NodeIndex,
         OptionalEdgeIndex,
     },
-    io::gfa1::PlainGfaEdgeData,
 };
 
+mod components;
+mod matrix;
+mod memory;
+mod node_set;
+mod overlap;
+#[cfg(feature = "std")]
+mod spell;
+pub mod stats;
 #[cfg(test)]
 mod tests;
-
-#[derive(Debug)]
+mod transform;
+pub mod traversal;
+mod validation;
+
+pub use matrix::AdjacencyMatrixError;
+pub use memory::{ArrayCapacity, CapacityReport};
+pub use node_set::{DirectedNodeSet, NodeSet};
+pub use overlap::OverlapError;
+#[cfg(feature = "std")]
+pub use spell::SpellError;
+pub use transform::MergeError;
+pub use validation::EdgeEndpointError;
+
+#[derive(Debug, Clone)]
 pub struct BidirectedAdjacencyArray<IndexType: GraphIndexInteger, NodeData, EdgeData> {
     /// Maps directed nodes to their edge lists.
     ///
@@ -56,7 +82,7 @@ struct EdgeDataKey<IndexType: GraphIndexInteger> {
     data_index: OptionalEdgeIndex<IndexType>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct BidirectedEdgeData<IndexType, EdgeData> {
     forward: DirectedEdgeIndex<IndexType>,
     reverse: DirectedEdgeIndex<IndexType>,
@@ -83,6 +109,18 @@ pub struct EdgeView<'a, IndexType, EdgeData> {
     data: &'a EdgeData,
 }
 
+/// The orientations of a self loop, as returned by [`BidirectedAdjacencyArray::self_loop_kind`].
+///
+/// The first sign is the orientation of `from`, and the second sign is the orientation of `to`, as in [`BidirectedEdge`].
+/// Note that [`Self::PlusPlus`] and [`Self::MinusMinus`] describe the same edge in reverse-complemental orientations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelfLoopKind {
+    PlusPlus,
+    MinusMinus,
+    PlusMinus,
+    MinusPlus,
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct BidirectedEdge<IndexType, EdgeData> {
     pub from: NodeIndex<IndexType>,
@@ -191,6 +229,11 @@ impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
         }
     }
 
+    /// Creates a graph without nodes and edges.
+    pub fn empty() -> Self {
+        Self::new(TaggedVec::new(), TaggedVec::new())
+    }
+
     pub fn node_count(&self) -> usize {
         self.node_data.len()
     }
@@ -199,14 +242,122 @@ impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
         self.edge_data.len()
     }
 
+    /// Returns the number of directed nodes, which is twice the number of bidirected nodes.
+    pub fn directed_node_count(&self) -> usize {
+        self.node_array.len() - 1
+    }
+
+    /// Returns the number of directed edges.
+    ///
+    /// Each bidirected edge is represented by two directed edges, including self loops
+    /// whose two directed edges are identical, so this is twice the number of bidirected edges.
+    pub fn directed_edge_count(&self) -> usize {
+        self.edge_array.len()
+    }
+
+    /// Returns true if the given node index is in range for this graph.
+    pub fn has_node(&self, node: NodeIndex<IndexType>) -> bool {
+        node.into_usize() < self.node_count()
+    }
+
+    /// Returns true if the given edge index is in range for this graph.
+    pub fn has_edge(&self, edge: EdgeIndex<IndexType>) -> bool {
+        edge.into_usize() < self.edge_count()
+    }
+
+    /// Returns true if the given directed node index is in range for this graph.
+    pub fn has_directed_node(&self, node: DirectedNodeIndex<IndexType>) -> bool {
+        node.into_usize() < self.directed_node_count()
+    }
+
+    /// Returns true if the given directed edge index is in range for this graph.
+    pub fn has_directed_edge(&self, edge: DirectedEdgeIndex<IndexType>) -> bool {
+        edge.into_usize() < self.directed_edge_count()
+    }
+
+    /// Returns the raw adjacency array as the slice of edge offsets per directed node and the slice of edge targets.
+    ///
+    /// The outgoing edges of directed node `n` are the targets at positions `offsets[n]..offsets[n + 1]`,
+    /// where the position in the target slice is the [`DirectedEdgeIndex`] of the edge.
+    /// Hence, the offset slice has one more entry than there are directed nodes.
+    /// Its last entry is a sentinel equal to the number of directed edges.
+    pub fn csr_parts(
+        &self,
+    ) -> (
+        &[DirectedEdgeIndex<IndexType>],
+        &[DirectedNodeIndex<IndexType>],
+    ) {
+        (
+            self.node_array.iter_values().as_slice(),
+            self.edge_array.iter_values().as_slice(),
+        )
+    }
+
+    /// Iterate over all directed nodes in order, each with the contiguous slice of the targets of its outgoing edges.
+    ///
+    /// The targets are in the same order as in [`Self::iter_outgoing_edges`].
+    pub fn iter_adjacency(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            DirectedNodeIndex<IndexType>,
+            &[DirectedNodeIndex<IndexType>],
+        ),
+    > {
+        let (offsets, targets) = self.csr_parts();
+        offsets.windows(2).enumerate().map(|(node, offsets)| {
+            (
+                DirectedNodeIndex::from_usize(node),
+                &targets[offsets[0].into_usize()..offsets[1].into_usize()],
+            )
+        })
+    }
+
     pub fn iter_nodes(&self) -> impl Iterator<Item = NodeIndex<IndexType>> {
         self.node_data.iter_indices()
     }
 
+    /// Iterate over all directed nodes in the order in which they are stored.
+    ///
+    /// This yields [`Self::directed_node_count`] directed nodes, with the forward directed node of each bidirected node directly followed by its reverse directed node.
+    pub fn iter_directed_nodes(&self) -> impl Iterator<Item = DirectedNodeIndex<IndexType>> {
+        (0..self.directed_node_count()).map(DirectedNodeIndex::from_usize)
+    }
+
     pub fn iter_edges(&self) -> impl Iterator<Item = EdgeIndex<IndexType>> {
         self.edge_data.iter_indices()
     }
 
+    /// Iterate over the nodes whose data fulfils the given predicate.
+    pub fn iter_nodes_where(
+        &self,
+        predicate: impl Fn(&NodeData) -> bool,
+    ) -> impl Iterator<Item = NodeIndex<IndexType>> {
+        self.iter_nodes()
+            .filter(move |&node| predicate(self.node_data(node)))
+    }
+
+    /// Iterate over the edges whose data fulfils the given predicate.
+    pub fn iter_edges_where(
+        &self,
+        predicate: impl Fn(&EdgeData) -> bool,
+    ) -> impl Iterator<Item = EdgeIndex<IndexType>> {
+        self.iter_edges()
+            .filter(move |&edge| predicate(&self.edge_data[edge].data))
+    }
+
+    /// Iterate over the bidirected nodes together with their data.
+    pub fn iter_node_data(&self) -> impl Iterator<Item = (NodeIndex<IndexType>, &NodeData)> {
+        self.node_data.iter()
+    }
+
+    /// Iterate over the bidirected edges as [`EdgeView`]s.
+    ///
+    /// This is equivalent to calling [`Self::edge`] on each index yielded by [`Self::iter_edges`].
+    pub fn iter_edge_views(&self) -> impl Iterator<Item = EdgeView<'_, IndexType, EdgeData>> {
+        self.iter_edges().map(|edge| self.edge(edge))
+    }
+
     pub fn iter_outgoing_edges(
         &self,
         node: DirectedNodeIndex<IndexType>,
@@ -224,33 +375,170 @@ impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
             })
     }
 
+    /// Iterate over the outgoing edges of the given directed node, paired with their data.
+    pub fn iter_outgoing_edges_with_data(
+        &self,
+        node: DirectedNodeIndex<IndexType>,
+    ) -> impl Iterator<Item = (DirectedEdge<IndexType>, &EdgeData)> {
+        self.iter_outgoing_edges(node).map(|edge| {
+            let data = self.directed_edge_data_ref(edge.index());
+            (edge, data)
+        })
+    }
+
+    /// Iterate over the successors of the given directed node, paired with the overlap of the connecting edge.
+    ///
+    /// The overlap is the same for both directions of a bidirected edge.
+    pub fn iter_successors_with_overlap(
+        &self,
+        node: DirectedNodeIndex<IndexType>,
+    ) -> impl Iterator<Item = (DirectedNodeIndex<IndexType>, u16)>
+    where
+        EdgeData: GfaEdgeData,
+    {
+        self.iter_outgoing_edges_with_data(node)
+            .map(|(edge, data)| (edge.to(), data.overlap()))
+    }
+
+    /// Iterate over all directed edges in the order in which they are stored.
+    ///
+    /// The edges are grouped by their source directed node, in ascending order of the source.
+    pub fn iter_directed_edges(&self) -> impl Iterator<Item = DirectedEdge<IndexType>> {
+        self.iter_directed_nodes()
+            .flat_map(|node| self.iter_outgoing_edges(node))
+    }
+
     /// Iterate over the bidirected edges incident to the given bidirected node.
     pub fn iter_incident_edges(
         &self,
         node: NodeIndex<IndexType>,
     ) -> impl Iterator<Item = EdgeIndex<IndexType>> {
+        self.iter_node_incidences(node).map(|(edge, _)| edge)
+    }
+
+    /// Returns the number of bidirected edges incident to the given bidirected node.
+    ///
+    /// This is the number of edges returned by [`Self::iter_incident_edges`], so each self loop counts once.
+    pub fn degree(&self, node: NodeIndex<IndexType>) -> usize {
+        self.iter_incident_edges(node).count()
+    }
+
+    /// Iterate over the bidirected edges incident to the given bidirected node, together with the side of the node they touch.
+    ///
+    /// The flag is true if the edge leaves the forward directed node of `node`,
+    /// i.e. if it is an outgoing edge when traversing `node` in forward direction.
+    /// Self loops are reported once, with the side from which their forward directed edge leaves.
+    pub fn iter_node_incidences(
+        &self,
+        node: NodeIndex<IndexType>,
+    ) -> impl Iterator<Item = (EdgeIndex<IndexType>, bool)> {
         let forward_node = DirectedNodeIndex::from_bidirected(node, true);
         let reverse_node = DirectedNodeIndex::from_bidirected(node, false);
         self.iter_outgoing_edges(forward_node)
             .chain(self.iter_outgoing_edges(reverse_node))
             .filter_map(|directed_edge| {
                 let directed_edge_data = self.directed_edge_data(directed_edge.index());
+                let incidence = (directed_edge_data.edge(), directed_edge.from().is_forward());
                 if directed_edge.from() == directed_edge.to()
                     || directed_edge.from() == directed_edge.to().invert()
                 {
-                    directed_edge_data
-                        .is_forward()
-                        .then_some(directed_edge_data.edge())
+                    directed_edge_data.is_forward().then_some(incidence)
                 } else {
-                    Some(directed_edge_data.edge())
+                    Some(incidence)
                 }
             })
     }
 
+    /// Iterate over the bidirected nodes adjacent to the given bidirected node, ignoring orientations.
+    ///
+    /// Each adjacent node is yielded once, in ascending order, even if it is connected by multiple edges.
+    /// If the node has a self loop, then it is adjacent to itself.
+    pub fn iter_adjacent_nodes(
+        &self,
+        node: NodeIndex<IndexType>,
+    ) -> impl Iterator<Item = NodeIndex<IndexType>> {
+        let mut adjacent_nodes = self
+            .iter_incident_edges(node)
+            .map(|edge| {
+                let edge = self.edge(edge);
+                let from = edge.from().into_bidirected();
+                if from == node {
+                    edge.to().into_bidirected()
+                } else {
+                    from
+                }
+            })
+            .collect::<Vec<_>>();
+        adjacent_nodes.sort_unstable();
+        adjacent_nodes.dedup();
+        adjacent_nodes.into_iter()
+    }
+
+    /// Finds a bidirected edge from the given side of `from` to the given side of `to`.
+    ///
+    /// The orientations have the same meaning as in [`BidirectedEdge`].
+    /// An edge is also found if it was constructed in its reverse-complemental orientation,
+    /// i.e. from `to` with flipped orientation to `from` with flipped orientation.
+    /// If there are parallel edges, then an arbitrary one of them is returned.
+    ///
+    /// This takes time linear in the number of outgoing edges of the directed node `(from, from_forward)`.
+    pub fn find_edge(
+        &self,
+        from: NodeIndex<IndexType>,
+        from_forward: bool,
+        to: NodeIndex<IndexType>,
+        to_forward: bool,
+    ) -> Option<EdgeIndex<IndexType>> {
+        let from = DirectedNodeIndex::from_bidirected(from, from_forward);
+        let to = DirectedNodeIndex::from_bidirected(to, to_forward);
+        self.iter_outgoing_edges(from)
+            .find(|directed_edge| directed_edge.to() == to)
+            .map(|directed_edge| self.directed_edge_into_bidirected(directed_edge.index()))
+    }
+
+    /// Returns true if there is a bidirected edge from the given side of `from` to the given side of `to`.
+    ///
+    /// See [`Self::find_edge`] for details.
+    pub fn contains_edge(
+        &self,
+        from: NodeIndex<IndexType>,
+        from_forward: bool,
+        to: NodeIndex<IndexType>,
+        to_forward: bool,
+    ) -> bool {
+        self.find_edge(from, from_forward, to, to_forward).is_some()
+    }
+
+    /// Counts the bidirected edges between `a` and `b` in any orientation.
+    ///
+    /// Parallel edges are counted individually.
+    /// If `a == b`, then the self loops of `a` are counted, each once.
+    pub fn edge_multiplicity(&self, a: NodeIndex<IndexType>, b: NodeIndex<IndexType>) -> usize {
+        self.iter_incident_edges(a)
+            .filter(|&edge| {
+                let edge = self.edge(edge);
+                let from = edge.from().into_bidirected();
+                let to = edge.to().into_bidirected();
+                (from == a && to == b) || (from == b && to == a)
+            })
+            .count()
+    }
+
     pub fn node_data(&self, node: NodeIndex<IndexType>) -> &NodeData {
         &self.node_data[node]
     }
 
+    pub fn node_data_mut(&mut self, node: NodeIndex<IndexType>) -> &mut NodeData {
+        &mut self.node_data[node]
+    }
+
+    /// Returns a mutable reference to the data of the given bidirected edge.
+    ///
+    /// Both directed edges of the bidirected edge share this data.
+    pub fn edge_data_mut(&mut self, edge: EdgeIndex<IndexType>) -> &mut EdgeData {
+        &mut self.edge_data[edge].data
+    }
+
     pub fn edge(&self, edge: EdgeIndex<IndexType>) -> EdgeView<'_, IndexType, EdgeData> {
         let bidirected_edge_data = &self.edge_data[edge];
 
@@ -293,30 +581,141 @@ impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
         }
     }
 
+    /// Iterate over the [`EdgeView`]s of the given edges, in the given order.
+    ///
+    /// This is equivalent to calling [`Self::edge`] on each index.
+    pub fn edges<'a>(
+        &'a self,
+        indices: &'a [EdgeIndex<IndexType>],
+    ) -> impl Iterator<Item = EdgeView<'a, IndexType, EdgeData>> {
+        indices.iter().map(|&edge| self.edge(edge))
+    }
+
+    /// Returns the orientations of the given edge if it is a self loop, or `None` otherwise.
+    ///
+    /// The orientations are those of [`Self::edge`], i.e. of the edge as it was passed to [`Self::new`].
+    pub fn self_loop_kind(&self, edge: EdgeIndex<IndexType>) -> Option<SelfLoopKind> {
+        let edge = self.edge(edge);
+        if edge.from().into_bidirected() != edge.to().into_bidirected() {
+            return None;
+        }
+
+        Some(match (edge.from().is_forward(), edge.to().is_forward()) {
+            (true, true) => SelfLoopKind::PlusPlus,
+            (false, false) => SelfLoopKind::MinusMinus,
+            (true, false) => SelfLoopKind::PlusMinus,
+            (false, true) => SelfLoopKind::MinusPlus,
+        })
+    }
+
+    /// Iterate over the self loops whose directed edges are loops on a single directed node.
+    ///
+    /// These are the self loops of kind [`SelfLoopKind::PlusPlus`] and [`SelfLoopKind::MinusMinus`],
+    /// i.e. edges from `n+` to `n+` in the notation of [`BidirectedEdge::new`], which lead from one end of the node back to its other end.
+    pub fn iter_hairpin_edges(&self) -> impl Iterator<Item = EdgeIndex<IndexType>> {
+        self.iter_edges().filter(|&edge| {
+            let (forward_to, reverse_to) = self.directed_edge_targets(edge);
+            forward_to.invert() == reverse_to
+        })
+    }
+
+    /// Iterate over the self loops that are their own reverse complement.
+    ///
+    /// These are the self loops of kind [`SelfLoopKind::PlusMinus`] and [`SelfLoopKind::MinusPlus`],
+    /// i.e. edges from `n+` to `n-` in the notation of [`BidirectedEdge::new`], whose two directed edges are identical.
+    pub fn iter_palindromic_edges(&self) -> impl Iterator<Item = EdgeIndex<IndexType>> {
+        self.iter_edges().filter(|&edge| {
+            let (forward_to, reverse_to) = self.directed_edge_targets(edge);
+            forward_to == reverse_to
+        })
+    }
+
+    /// Returns the targets of the forward and reverse directed edges of the given bidirected edge.
+    fn directed_edge_targets(
+        &self,
+        edge: EdgeIndex<IndexType>,
+    ) -> (DirectedNodeIndex<IndexType>, DirectedNodeIndex<IndexType>) {
+        let (forward, reverse) = self.directed_edges(edge);
+        (self.edge_array[forward], self.edge_array[reverse])
+    }
+
+    /// Returns the forward and reverse directed edges of the given bidirected edge.
+    ///
+    /// These are the same as [`EdgeView::forward`] and [`EdgeView::reverse`], without computing the endpoints.
+    pub fn directed_edges(
+        &self,
+        edge: EdgeIndex<IndexType>,
+    ) -> (DirectedEdgeIndex<IndexType>, DirectedEdgeIndex<IndexType>) {
+        let bidirected_edge_data = &self.edge_data[edge];
+        (bidirected_edge_data.forward, bidirected_edge_data.reverse)
+    }
+
+    /// Returns the endpoints of the given edge as a [`BidirectedEdge`] that borrows the edge data.
+    ///
+    /// The endpoints are the same as those of [`Self::edge`], so the result matches the edge passed to [`Self::new`].
+    pub fn bidirected_edge(
+        &self,
+        edge: EdgeIndex<IndexType>,
+    ) -> BidirectedEdge<IndexType, &EdgeData> {
+        let edge = self.edge(edge);
+        BidirectedEdge::new(edge.from(), edge.to(), edge.data)
+    }
+
     pub fn directed_edge_data<'this>(
         &'this self,
         directed_edge: DirectedEdgeIndex<IndexType>,
     ) -> DirectedEdgeDataView<'this, IndexType, EdgeData> {
-        let key = &self.edge_data_keys[directed_edge];
-        if let Some(edge) = key.data_index.into_option() {
-            DirectedEdgeDataView {
-                is_forward: true,
-                edge,
-                data: &self.edge_data[edge].data,
-            }
-        } else {
-            let inverse_key = &self.edge_data_keys[key.inverse];
-            let Some(edge) = inverse_key.data_index.into_option() else {
+        self.try_directed_edge_data(directed_edge)
+            .unwrap_or_else(|| {
                 panic!(
                     "Edge data for edge {:?} and its inverse {:?} are both missing",
-                    directed_edge, key.inverse
-                );
-            };
-            DirectedEdgeDataView {
-                is_forward: false,
+                    directed_edge, self.edge_data_keys[directed_edge].inverse
+                )
+            })
+    }
+
+    /// Returns only the data of the given directed edge.
+    ///
+    /// This is a shorthand for [`Self::directed_edge_data`] followed by [`DirectedEdgeDataView::data`].
+    pub fn directed_edge_data_ref(&self, directed_edge: DirectedEdgeIndex<IndexType>) -> &EdgeData {
+        self.directed_edge_data(directed_edge).data()
+    }
+
+    /// Returns the data of the given directed edge, or `None` if it cannot be resolved.
+    ///
+    /// In a graph constructed with [`Self::new`], this only returns `None` if the directed edge does not exist.
+    /// Unlike [`Self::directed_edge_data`], this never panics, even if the internal structure is inconsistent.
+    pub fn try_directed_edge_data<'this>(
+        &'this self,
+        directed_edge: DirectedEdgeIndex<IndexType>,
+    ) -> Option<DirectedEdgeDataView<'this, IndexType, EdgeData>> {
+        let key = self.try_edge_data_key(directed_edge)?;
+        let (is_forward, edge) = if let Some(edge) = key.data_index.into_option() {
+            (true, edge)
+        } else {
+            let inverse_key = self.try_edge_data_key(key.inverse)?;
+            (false, inverse_key.data_index.into_option()?)
+        };
+
+        if edge.into_usize() < self.edge_data.len() {
+            Some(DirectedEdgeDataView {
+                is_forward,
                 edge,
                 data: &self.edge_data[edge].data,
-            }
+            })
+        } else {
+            None
+        }
+    }
+
+    fn try_edge_data_key(
+        &self,
+        directed_edge: DirectedEdgeIndex<IndexType>,
+    ) -> Option<&EdgeDataKey<IndexType>> {
+        if directed_edge.into_usize() < self.edge_data_keys.len() {
+            Some(&self.edge_data_keys[directed_edge])
+        } else {
+            None
         }
     }
 
@@ -324,16 +723,72 @@ impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
         &self,
         directed_edge: DirectedEdgeIndex<IndexType>,
     ) -> EdgeIndex<IndexType> {
+        self.directed_edge_into_bidirected_oriented(directed_edge).0
+    }
+
+    /// Returns the bidirected edge of the given directed edge, and true if the directed edge is its forward representative.
+    ///
+    /// The forward representative goes from [`EdgeView::from`] to [`EdgeView::to`] of the bidirected edge,
+    /// and the reverse representative goes in the opposite direction over the inverted nodes.
+    pub fn directed_edge_into_bidirected_oriented(
+        &self,
+        directed_edge: DirectedEdgeIndex<IndexType>,
+    ) -> (EdgeIndex<IndexType>, bool) {
         let key = &self.edge_data_keys[directed_edge];
         if let Some(edge) = key.data_index.into_option() {
-            edge
+            (edge, true)
         } else {
             let inverse_key = &self.edge_data_keys[key.inverse];
-            inverse_key
+            let edge = inverse_key
                 .data_index
-                .expect("Edge data for directed edge and its inverse are both missing")
+                .expect("Edge data for directed edge and its inverse are both missing");
+            (edge, false)
         }
     }
+
+    /// Returns the reverse-complemental partner of the given directed edge.
+    ///
+    /// If the directed edge goes from `a` to `b`, then its inverse goes from `\hat{b}` to `\hat{a}`.
+    /// Both represent the same bidirected edge, and inverting twice yields the original directed edge.
+    /// The two directed edges of a self loop from `n+` to `n-` are distinct but have the same endpoints.
+    pub fn inverse_directed_edge(
+        &self,
+        directed_edge: DirectedEdgeIndex<IndexType>,
+    ) -> DirectedEdgeIndex<IndexType> {
+        self.edge_data_keys[directed_edge].inverse
+    }
+
+    /// Deconstructs the graph into the node and edge lists it can be constructed from with [`Self::new`].
+    ///
+    /// Passing the result to [`Self::new`] yields a graph equal to this one under [`Self::compare`],
+    /// including the node and edge indices.
+    #[allow(clippy::type_complexity)]
+    pub fn into_edges(
+        self,
+    ) -> (
+        TaggedVec<NodeIndex<IndexType>, NodeData>,
+        TaggedVec<EdgeIndex<IndexType>, BidirectedEdge<IndexType, EdgeData>>,
+    ) {
+        let endpoints = self
+            .iter_edge_views()
+            .map(|edge| (edge.from(), edge.to()))
+            .collect::<Vec<_>>();
+        let edges = endpoints
+            .into_iter()
+            .zip(self.edge_data.into_values_iter())
+            .map(|((from, to), edge_data)| BidirectedEdge::new(from, to, edge_data.data))
+            .collect();
+
+        (self.node_data, edges)
+    }
+}
+
+impl<IndexType: GraphIndexInteger, NodeData, EdgeData> Default
+    for BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    fn default() -> Self {
+        Self::empty()
+    }
 }
 
 impl<IndexType> DirectedEdge<IndexType> {
@@ -371,7 +826,7 @@ impl<'a, IndexType, EdgeData> DirectedEdgeDataView<'a, IndexType, EdgeData> {
         self.edge
     }
 
-    pub fn data(&self) -> &EdgeData {
+    pub fn data(&self) -> &'a EdgeData {
         self.data
     }
 }
@@ -405,7 +860,27 @@ impl<'a, IndexType, EdgeData> EdgeView<'a, IndexType, EdgeData> {
         self.reverse
     }
 
-    pub fn data(&self) -> &EdgeData {
+    /// Returns the bidirected node this edge originates from, and true if it originates from its forward side.
+    ///
+    /// This matches the `from` and `from_forward` fields of [`BidirectedEdge`].
+    pub fn from_bidirected(&self) -> (NodeIndex<IndexType>, bool)
+    where
+        IndexType: GraphIndexInteger,
+    {
+        (self.from.into_bidirected(), self.from.is_forward())
+    }
+
+    /// Returns the bidirected node this edge terminates at, and true if it terminates at its forward side.
+    ///
+    /// This matches the `to` and `to_forward` fields of [`BidirectedEdge`].
+    pub fn to_bidirected(&self) -> (NodeIndex<IndexType>, bool)
+    where
+        IndexType: GraphIndexInteger,
+    {
+        (self.to.into_bidirected(), self.to.is_forward())
+    }
+
+    pub fn data(&self) -> &'a EdgeData {
         self.data
     }
 }
@@ -424,8 +899,57 @@ impl<IndexType: GraphIndexInteger, EdgeData> BidirectedEdge<IndexType, EdgeData>
             data,
         }
     }
+
+    /// Returns this edge in its canonical orientation.
+    ///
+    /// A bidirected edge `(a, b)` between directed nodes `a` and `b` is the same edge as its
+    /// reverse complement `(\hat{b}, \hat{a})`, where `\hat{x}` is `x` with flipped orientation.
+    /// In terms of the fields, `(from, from_forward, to, to_forward)` is the same edge as
+    /// `(to, !to_forward, from, !from_forward)`.
+    ///
+    /// The canonical orientation is the one whose `(from, to)` tuple of directed nodes is lexicographically smaller,
+    /// where directed nodes are ordered by their bidirected node and then forward before reverse.
+    /// Hence, two edges are the same up to orientation if and only if their canonical forms are [structurally equal](Self::structural_eq).
+    pub fn canonical(self) -> Self {
+        let from = DirectedNodeIndex::from_bidirected(self.from, self.from_forward);
+        let to = DirectedNodeIndex::from_bidirected(self.to, self.to_forward);
+        let (from, to) = (from, to).min((to.invert(), from.invert()));
+        Self::new(from, to, self.data)
+    }
+}
+
+impl<IndexType, EdgeData> BidirectedEdge<IndexType, EdgeData> {
+    /// Returns true if both edges have the same endpoints with the same orientations, ignoring their data.
+    ///
+    /// An edge is not structurally equal to its reverse-complemental orientation.
+    pub fn structural_eq<OtherEdgeData>(
+        &self,
+        other: &BidirectedEdge<IndexType, OtherEdgeData>,
+    ) -> bool
+    where
+        IndexType: PartialEq,
+    {
+        self.from == other.from
+            && self.from_forward == other.from_forward
+            && self.to == other.to
+            && self.to_forward == other.to_forward
+    }
+
+    /// Hashes the endpoints and orientations of the edge, ignoring its data.
+    ///
+    /// This is consistent with [`Self::structural_eq`].
+    pub fn structural_hash<State: Hasher>(&self, state: &mut State)
+    where
+        IndexType: Hash,
+    {
+        self.from.hash(state);
+        self.from_forward.hash(state);
+        self.to.hash(state);
+        self.to_forward.hash(state);
+    }
 }
 
+#[cfg(feature = "std")]
 impl<IndexType: GraphIndexInteger> BidirectedEdge<IndexType, PlainGfaEdgeData> {
     pub fn new_gfa(
         from: DirectedNodeIndex<IndexType>,
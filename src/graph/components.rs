@@ -0,0 +1,389 @@
+use alloc::{collections::BTreeSet, vec, vec::Vec};
+use core::iter;
+
+use tagged_vec::TaggedVec;
+
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge, NodeSet},
+    index::{DirectedNodeIndex, EdgeIndex, GraphIndexInteger, NodeIndex},
+};
+
+#[cfg(test)]
+mod tests;
+
+impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
+    BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    /// Counts the weakly connected components of the graph.
+    ///
+    /// Two bidirected nodes are in the same component if they are connected by a path of edges,
+    /// ignoring the sides and directions of the edges.
+    pub fn count_weakly_connected_components(&self) -> usize {
+        let union_find = self.weakly_connected_union_find();
+        self.iter_nodes()
+            .filter(|&node| union_find.is_root(node))
+            .count()
+    }
+
+    /// Iterate over the weakly connected components of the graph, each as the list of its nodes.
+    ///
+    /// The nodes of each component are in ascending order,
+    /// and the components are ordered by their smallest node.
+    /// All components are computed up front.
+    pub fn iter_weakly_connected_components(
+        &self,
+    ) -> impl Iterator<Item = Vec<NodeIndex<IndexType>>> {
+        let mut union_find = self.weakly_connected_union_find();
+        let mut root_to_component = TaggedVec::<NodeIndex<IndexType>, Option<usize>>::from_iter(
+            iter::repeat_n(None, self.node_count()),
+        );
+        let mut components = Vec::<Vec<_>>::new();
+
+        for node in self.iter_nodes() {
+            let root = union_find.find(node);
+            let component = *root_to_component[root].get_or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[component].push(node);
+        }
+
+        components.into_iter()
+    }
+
+    /// Labels each directed node with its strongly connected component using Tarjan's algorithm.
+    ///
+    /// Two directed nodes are in the same component if each is reachable from the other.
+    /// The components are numbered from zero in reverse topological order,
+    /// i.e. every edge between different components goes from a higher to a lower label.
+    ///
+    /// Since every path has a reverse-complemental path, the reverse complement of a component is again a component.
+    /// It may be the component itself, e.g. if there are self loops from `n+` to `n-` and from `n-` to `n+`.
+    pub fn strongly_connected_components(&self) -> TaggedVec<DirectedNodeIndex<IndexType>, usize> {
+        let directed_node_count = self.directed_node_count();
+        let mut indices = TaggedVec::<DirectedNodeIndex<IndexType>, Option<usize>>::from_iter(
+            iter::repeat_n(None, directed_node_count),
+        );
+        let mut lowlinks = TaggedVec::<DirectedNodeIndex<IndexType>, usize>::from_iter(
+            iter::repeat_n(0, directed_node_count),
+        );
+        let mut labels = TaggedVec::<DirectedNodeIndex<IndexType>, Option<usize>>::from_iter(
+            iter::repeat_n(None, directed_node_count),
+        );
+        let mut next_index = 0;
+        let mut component_count = 0;
+        let mut component_stack = Vec::new();
+        let mut call_stack = Vec::new();
+
+        for root in 0..directed_node_count {
+            let root = DirectedNodeIndex::from_usize(root);
+            if indices[root].is_some() {
+                continue;
+            }
+            indices[root] = Some(next_index);
+            lowlinks[root] = next_index;
+            next_index += 1;
+            component_stack.push(root);
+            call_stack.push((root, self.iter_outgoing_edges(root)));
+
+            while let Some((node, outgoing_edges)) = call_stack.last_mut() {
+                let node = *node;
+                if let Some(edge) = outgoing_edges.next() {
+                    let successor = edge.to();
+                    if let Some(successor_index) = indices[successor] {
+                        // Only successors without label are still on the component stack.
+                        if labels[successor].is_none() {
+                            lowlinks[node] = lowlinks[node].min(successor_index);
+                        }
+                    } else {
+                        indices[successor] = Some(next_index);
+                        lowlinks[successor] = next_index;
+                        next_index += 1;
+                        component_stack.push(successor);
+                        call_stack.push((successor, self.iter_outgoing_edges(successor)));
+                    }
+                } else {
+                    call_stack.pop();
+                    if let Some((parent, _)) = call_stack.last() {
+                        lowlinks[*parent] = lowlinks[*parent].min(lowlinks[node]);
+                    }
+
+                    if indices[node] == Some(lowlinks[node]) {
+                        while let Some(member) = component_stack.pop() {
+                            labels[member] = Some(component_count);
+                            if member == node {
+                                break;
+                            }
+                        }
+                        component_count += 1;
+                    }
+                }
+            }
+        }
+
+        labels.into_values_iter().map(Option::unwrap).collect()
+    }
+
+    /// Computes the condensation of the graph, in which each strongly connected component is collapsed into a single node.
+    ///
+    /// The condensation is again a bidirected graph.
+    /// Each of its bidirected nodes represents a pair of reverse-complemental components of [`Self::strongly_connected_components`],
+    /// with its forward side representing the component that contains the smallest directed node of the pair.
+    /// The node data is the sorted list of the directed nodes in that component,
+    /// so the reverse side represents the inverse directed nodes of the list.
+    /// A self-complementary component becomes a node whose sides both represent the whole component,
+    /// and its node data lists the directed nodes of both orientations.
+    ///
+    /// There is one edge for each pair of adjacent components, regardless of how many edges connect them,
+    /// and edges within a component are dropped.
+    /// The edges are in [canonical orientation](BidirectedEdge::canonical).
+    /// An edge into a self-complementary component may be attached to either side of its node,
+    /// such that there may be two edges from the same component to its two sides.
+    /// If no component is self-complementary, then the directed representation of the condensation is acyclic.
+    pub fn condensation(
+        &self,
+    ) -> BidirectedAdjacencyArray<IndexType, Vec<DirectedNodeIndex<IndexType>>, ()> {
+        let labels = self.strongly_connected_components();
+        let component_count = labels.iter_values().max().map_or(0, |label| label + 1);
+
+        let mut component_nodes = vec![None; component_count];
+        let mut nodes = TaggedVec::<NodeIndex<IndexType>, Vec<_>>::new();
+        for node in self.iter_directed_nodes() {
+            let label = labels[node];
+            let condensed_node = if let Some(condensed_node) = component_nodes[label] {
+                condensed_node
+            } else {
+                let condensed_node = nodes.push(Vec::new());
+                let inverse_label = labels[node.invert()];
+                if inverse_label != label {
+                    component_nodes[inverse_label] =
+                        Some(DirectedNodeIndex::from_bidirected(condensed_node, false));
+                }
+                let condensed_node = DirectedNodeIndex::from_bidirected(condensed_node, true);
+                component_nodes[label] = Some(condensed_node);
+                condensed_node
+            };
+
+            if condensed_node.is_forward() {
+                nodes[condensed_node.into_bidirected()].push(node);
+            }
+        }
+
+        let mut contained_edges = BTreeSet::new();
+        let mut edges = TaggedVec::new();
+        for edge in self.iter_directed_edges() {
+            if labels[edge.from()] == labels[edge.to()] {
+                continue;
+            }
+
+            let from = component_nodes[labels[edge.from()]].unwrap();
+            let to = component_nodes[labels[edge.to()]].unwrap();
+            if contained_edges.insert((from, to).min((to.invert(), from.invert()))) {
+                edges.push(BidirectedEdge::new(from, to, ()).canonical());
+            }
+        }
+
+        BidirectedAdjacencyArray::new(nodes, edges)
+    }
+
+    /// Returns the bridges of the graph in ascending order.
+    ///
+    /// A bridge is an edge whose removal increases the number of weakly connected components,
+    /// see [`Self::count_weakly_connected_components`].
+    /// This ignores the sides and directions of the edges, so self loops and parallel edges are never bridges,
+    /// even if parallel edges attach to different sides of their endpoints.
+    pub fn bridges(&self) -> Vec<EdgeIndex<IndexType>> {
+        let mut bridges = Vec::new();
+        self.undirected_depth_first_search(|tree_edge| {
+            if tree_edge.child_lowlink > tree_edge.parent_discovery_time {
+                bridges.push(tree_edge.edge);
+            }
+        });
+
+        bridges.sort_unstable();
+        bridges
+    }
+
+    /// Returns the articulation points of the graph in ascending order.
+    ///
+    /// An articulation point is a node whose removal together with its incident edges
+    /// increases the number of weakly connected components, see [`Self::count_weakly_connected_components`].
+    /// Like [`Self::bridges`], this ignores the sides and directions of the edges.
+    pub fn articulation_points(&self) -> Vec<NodeIndex<IndexType>> {
+        let mut articulation_points = NodeSet::new(self.node_count());
+        let mut root_child_counts = TaggedVec::<NodeIndex<IndexType>, usize>::from_iter(
+            iter::repeat_n(0, self.node_count()),
+        );
+        self.undirected_depth_first_search(|tree_edge| {
+            if tree_edge.parent_is_root {
+                // The root of a search tree is an articulation point if it has more than one child.
+                root_child_counts[tree_edge.parent] += 1;
+                if root_child_counts[tree_edge.parent] == 2 {
+                    articulation_points.insert(tree_edge.parent);
+                }
+            } else if tree_edge.child_lowlink >= tree_edge.parent_discovery_time {
+                articulation_points.insert(tree_edge.parent);
+            }
+        });
+
+        articulation_points.iter().collect()
+    }
+
+    /// Returns the edges of a minimum spanning forest of the graph, computed with Kruskal's algorithm.
+    ///
+    /// Like [`Self::bridges`], this ignores the sides and directions of the edges,
+    /// and the forest contains a spanning tree of each weakly connected component.
+    /// The edges are returned in the order in which they are selected, i.e. by ascending weight.
+    /// Edges of equal weight are considered in ascending order of their index,
+    /// so the result is deterministic even if there are multiple minimum spanning forests.
+    pub fn minimum_spanning_forest<Weight: Ord>(
+        &self,
+        weight: impl Fn(&EdgeData) -> Weight,
+    ) -> Vec<EdgeIndex<IndexType>> {
+        let mut edges = self.iter_edges().collect::<Vec<_>>();
+        // The sort is stable, so edges of equal weight stay in ascending order of their index.
+        edges.sort_by_cached_key(|&edge| weight(self.edge(edge).data()));
+
+        let mut union_find = UnionFind::new(self.node_count());
+        edges
+            .into_iter()
+            .filter(|&edge| {
+                let edge = self.edge(edge);
+                union_find.union(edge.from().into_bidirected(), edge.to().into_bidirected())
+            })
+            .collect()
+    }
+
+    /// Runs an iterative depth-first search over the undirected projection of the graph,
+    /// calling `on_tree_edge` for each edge of the search forest once the subtree below it has been searched.
+    ///
+    /// Only the edge to the parent itself is skipped when searching a node, such that parallel edges close a cycle.
+    fn undirected_depth_first_search(&self, mut on_tree_edge: impl FnMut(TreeEdge<IndexType>)) {
+        let mut discovery_times = TaggedVec::<NodeIndex<IndexType>, Option<usize>>::from_iter(
+            iter::repeat_n(None, self.node_count()),
+        );
+        let mut lowlinks = TaggedVec::<NodeIndex<IndexType>, usize>::from_iter(iter::repeat_n(
+            0,
+            self.node_count(),
+        ));
+        let mut next_discovery_time = 0;
+        let mut stack = Vec::new();
+
+        for root in self.iter_nodes() {
+            if discovery_times[root].is_some() {
+                continue;
+            }
+            discovery_times[root] = Some(next_discovery_time);
+            lowlinks[root] = next_discovery_time;
+            next_discovery_time += 1;
+            stack.push((root, None, self.iter_incident_edges(root)));
+
+            while let Some((node, parent_edge, incident_edges)) = stack.last_mut() {
+                let (node, parent_edge) = (*node, *parent_edge);
+                if let Some(edge) = incident_edges.next() {
+                    if Some(edge) == parent_edge {
+                        continue;
+                    }
+
+                    let edge_view = self.edge(edge);
+                    let from = edge_view.from().into_bidirected();
+                    let to = edge_view.to().into_bidirected();
+                    let neighbour = if from == node { to } else { from };
+
+                    if let Some(discovery_time) = discovery_times[neighbour] {
+                        lowlinks[node] = lowlinks[node].min(discovery_time);
+                    } else {
+                        discovery_times[neighbour] = Some(next_discovery_time);
+                        lowlinks[neighbour] = next_discovery_time;
+                        next_discovery_time += 1;
+                        stack.push((neighbour, Some(edge), self.iter_incident_edges(neighbour)));
+                    }
+                } else {
+                    stack.pop();
+                    if let Some((parent, _, _)) = stack.last() {
+                        let parent = *parent;
+                        lowlinks[parent] = lowlinks[parent].min(lowlinks[node]);
+                        on_tree_edge(TreeEdge {
+                            parent,
+                            parent_is_root: stack.len() == 1,
+                            parent_discovery_time: discovery_times[parent].unwrap(),
+                            edge: parent_edge.unwrap(),
+                            child_lowlink: lowlinks[node],
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Builds a union-find structure in which each set is a weakly connected component.
+    pub(crate) fn weakly_connected_union_find(&self) -> UnionFind<IndexType> {
+        let mut union_find = UnionFind::new(self.node_count());
+        for edge in self.iter_edge_views() {
+            union_find.union(edge.from().into_bidirected(), edge.to().into_bidirected());
+        }
+        union_find
+    }
+}
+
+/// An edge of the search forest of [`BidirectedAdjacencyArray::undirected_depth_first_search`], from a parent to a child.
+struct TreeEdge<IndexType> {
+    parent: NodeIndex<IndexType>,
+    parent_is_root: bool,
+    parent_discovery_time: usize,
+    edge: EdgeIndex<IndexType>,
+    /// The smallest discovery time reachable from the subtree of the child via at most one non-tree edge.
+    child_lowlink: usize,
+}
+
+/// A disjoint-set forest over bidirected nodes with union by rank and path halving.
+pub(crate) struct UnionFind<IndexType> {
+    parents: TaggedVec<NodeIndex<IndexType>, NodeIndex<IndexType>>,
+    ranks: TaggedVec<NodeIndex<IndexType>, u8>,
+}
+
+impl<IndexType: GraphIndexInteger> UnionFind<IndexType> {
+    /// Creates a union-find structure where each of the given number of nodes is in its own set.
+    pub(crate) fn new(node_count: usize) -> Self {
+        Self {
+            parents: (0..node_count).map(NodeIndex::from_usize).collect(),
+            ranks: iter::repeat_n(0, node_count).collect(),
+        }
+    }
+
+    /// Returns the representative of the set containing `node`.
+    pub(crate) fn find(&mut self, mut node: NodeIndex<IndexType>) -> NodeIndex<IndexType> {
+        while self.parents[node] != node {
+            let grandparent = self.parents[self.parents[node]];
+            self.parents[node] = grandparent;
+            node = grandparent;
+        }
+        node
+    }
+
+    /// Merges the sets containing `a` and `b`.
+    ///
+    /// Returns `false` if they were already in the same set.
+    pub(crate) fn union(&mut self, a: NodeIndex<IndexType>, b: NodeIndex<IndexType>) -> bool {
+        let a = self.find(a);
+        let b = self.find(b);
+        if a == b {
+            return false;
+        }
+
+        if self.ranks[a] < self.ranks[b] {
+            self.parents[a] = b;
+        } else if self.ranks[a] > self.ranks[b] {
+            self.parents[b] = a;
+        } else {
+            self.parents[b] = a;
+            self.ranks[a] += 1;
+        }
+        true
+    }
+
+    /// Returns true if `node` is the representative of its set.
+    pub(crate) fn is_root(&self, node: NodeIndex<IndexType>) -> bool {
+        self.parents[node] == node
+    }
+}
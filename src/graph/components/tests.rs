@@ -0,0 +1,204 @@
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge},
+    test_util::{directed, unit_graph},
+};
+
+#[test]
+fn test_count_weakly_connected_components() {
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(Default::default(), Default::default());
+    assert_eq!(graph.count_weakly_connected_components(), 0);
+
+    let graph = unit_graph(
+        6,
+        &[
+            (directed(0, true), directed(1, false)),
+            (directed(2, false), directed(1, false)),
+            (directed(3, true), directed(3, false)),
+            (directed(4, false), directed(5, false)),
+        ],
+    );
+    assert_eq!(graph.count_weakly_connected_components(), 3);
+    assert_eq!(
+        graph.iter_weakly_connected_components().collect::<Vec<_>>(),
+        vec![
+            vec![0.into(), 1.into(), 2.into()],
+            vec![3.into()],
+            vec![4.into(), 5.into()],
+        ]
+    );
+}
+
+/// Two reverse-complemental cycles 0 -> 1 -> 2 -> 0 and 3 <-> 4, connected by two edges,
+/// and a self-complementary component of node 5 reachable from 4.
+fn cyclic_graph() -> BidirectedAdjacencyArray<u8, (), ()> {
+    unit_graph(
+        6,
+        &[
+            (directed(0, true), directed(1, true)),
+            (directed(1, true), directed(2, true)),
+            (directed(2, true), directed(0, true)),
+            (directed(3, true), directed(4, true)),
+            (directed(4, true), directed(3, true)),
+            (directed(2, true), directed(3, true)),
+            (directed(4, false), directed(1, false)),
+            (directed(4, true), directed(5, true)),
+            (directed(5, true), directed(5, false)),
+            (directed(5, false), directed(5, true)),
+        ],
+    )
+}
+
+#[test]
+fn test_strongly_connected_components() {
+    let graph = cyclic_graph();
+    let labels = graph.strongly_connected_components();
+
+    assert_eq!(labels.iter_values().max(), Some(&4));
+    assert_eq!(labels[directed(0, true)], labels[directed(2, true)]);
+    assert_ne!(labels[directed(0, true)], labels[directed(0, false)]);
+    assert_eq!(labels[directed(1, false)], labels[directed(2, false)]);
+    assert_eq!(labels[directed(3, false)], labels[directed(4, false)]);
+    assert_ne!(labels[directed(2, true)], labels[directed(3, true)]);
+    assert_eq!(labels[directed(5, true)], labels[directed(5, false)]);
+    for edge in graph.iter_directed_edges() {
+        assert!(labels[edge.from()] >= labels[edge.to()]);
+    }
+
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::default();
+    assert!(graph.strongly_connected_components().is_empty());
+}
+
+#[test]
+fn test_condensation() {
+    let graph = cyclic_graph();
+    let condensation = graph.condensation();
+
+    let nodes = vec![
+        vec![directed(0, true), directed(1, true), directed(2, true)],
+        vec![directed(3, true), directed(4, true)],
+        vec![directed(5, true), directed(5, false)],
+    ];
+    let edges = vec![
+        BidirectedEdge::new(directed(0, true), directed(1, true), ()),
+        BidirectedEdge::new(directed(1, true), directed(2, true), ()),
+        BidirectedEdge::new(directed(1, true), directed(2, false), ()),
+    ];
+    let expected = BidirectedAdjacencyArray::new(nodes.into(), edges.into());
+    assert_eq!(condensation, expected);
+
+    // Without self-complementary components, the condensation is acyclic.
+    let graph = unit_graph(
+        3,
+        &[
+            (directed(0, true), directed(1, false)),
+            (directed(1, false), directed(0, true)),
+            (directed(2, true), directed(1, true)),
+        ],
+    );
+    let condensation = graph.condensation();
+    assert_eq!(condensation.node_count(), 2);
+    assert_eq!(
+        condensation.node_data(0.into()),
+        &vec![directed(0, true), directed(1, false)],
+    );
+    assert_eq!(condensation.node_data(1.into()), &vec![directed(2, true)]);
+    assert_eq!(condensation.edge_count(), 1);
+    assert!(condensation.is_dag());
+}
+
+#[test]
+fn test_bridges() {
+    let graph = unit_graph(
+        7,
+        &[
+            // A triangle 0, 1, 2 attached to node 3 by a bridge.
+            (directed(0, true), directed(1, false)),
+            (directed(1, true), directed(2, true)),
+            (directed(2, false), directed(0, false)),
+            (directed(2, true), directed(3, true)),
+            // Parallel edges between 3 and 4 on different sides are not bridges.
+            (directed(3, true), directed(4, true)),
+            (directed(4, false), directed(3, false)),
+            // A bridge to node 5 with a self loop.
+            (directed(4, true), directed(5, false)),
+            (directed(5, true), directed(5, false)),
+        ],
+    );
+
+    assert_eq!(graph.bridges(), vec![3.into(), 6.into()]);
+    assert!(
+        BidirectedAdjacencyArray::<u8, (), ()>::empty()
+            .bridges()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_articulation_points() {
+    let graph = unit_graph(
+        4,
+        &[
+            (directed(0, true), directed(1, true)),
+            (directed(1, true), directed(2, false)),
+            (directed(2, true), directed(3, true)),
+        ],
+    );
+    assert_eq!(graph.articulation_points(), vec![1.into(), 2.into()]);
+
+    // The search starts at node 0, which has two children in the search tree.
+    let graph = unit_graph(
+        5,
+        &[
+            (directed(0, true), directed(1, true)),
+            (directed(0, false), directed(2, true)),
+            (directed(2, true), directed(3, true)),
+            (directed(3, true), directed(2, false)),
+            (directed(4, true), directed(4, true)),
+        ],
+    );
+    assert_eq!(graph.articulation_points(), vec![0.into(), 2.into()]);
+
+    // In a cycle, no node is an articulation point.
+    let graph = unit_graph(
+        3,
+        &[
+            (directed(0, true), directed(1, true)),
+            (directed(1, true), directed(2, true)),
+            (directed(2, true), directed(0, true)),
+        ],
+    );
+    assert!(graph.articulation_points().is_empty());
+    assert!(graph.bridges().is_empty());
+}
+
+#[test]
+fn test_minimum_spanning_forest() {
+    let nodes = vec![(); 7];
+    let edges = vec![
+        // A triangle 0, 1, 2 where edge 0 is the heaviest.
+        BidirectedEdge::new(directed(0, true), directed(1, false), 5),
+        BidirectedEdge::new(directed(1, true), directed(2, true), 1),
+        BidirectedEdge::new(directed(2, false), directed(0, false), 1),
+        BidirectedEdge::new(directed(2, true), directed(3, true), 2),
+        // Self loops never join two trees.
+        BidirectedEdge::new(directed(3, true), directed(3, false), 0),
+        // A separate component with parallel edges of equal weight, where the lower index wins.
+        BidirectedEdge::new(directed(4, true), directed(5, true), 7),
+        BidirectedEdge::new(directed(5, false), directed(4, false), 7),
+    ];
+    let graph = BidirectedAdjacencyArray::<u8, (), u8>::new(nodes.into(), edges.into());
+
+    assert_eq!(
+        graph.minimum_spanning_forest(|&weight| weight),
+        vec![1.into(), 2.into(), 3.into(), 5.into()]
+    );
+    assert_eq!(
+        graph.minimum_spanning_forest(|&weight| std::cmp::Reverse(weight)),
+        vec![5.into(), 0.into(), 3.into(), 1.into()]
+    );
+    assert!(
+        BidirectedAdjacencyArray::<u8, (), u8>::empty()
+            .minimum_spanning_forest(|&weight| weight)
+            .is_empty()
+    );
+}
@@ -0,0 +1,48 @@
+use alloc::{vec, vec::Vec};
+
+use thiserror::Error;
+
+use crate::{graph::BidirectedAdjacencyArray, index::GraphIndexInteger};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AdjacencyMatrixError {
+    #[error(
+        "the graph has {0} nodes, which is more than the maximum of {1} for an adjacency matrix"
+    )]
+    TooManyNodes(usize, usize),
+}
+
+impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
+    BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    /// Returns the adjacency matrix of the directed representation of the graph.
+    ///
+    /// Entry `[i][j]` is the number of directed edges from directed node `i` to directed node `j`,
+    /// saturating at [`u8::MAX`].
+    /// Both directed edges of each bidirected edge are counted,
+    /// so a self loop from `n+` to `n-` contributes two to the entry of `n+` and `n-`.
+    ///
+    /// The matrix takes memory quadratic in the number of nodes.
+    /// Hence, if the graph has more than `max_nodes` bidirected nodes, then an error is returned instead.
+    pub fn to_adjacency_matrix(
+        &self,
+        max_nodes: usize,
+    ) -> Result<Vec<Vec<u8>>, AdjacencyMatrixError> {
+        if self.node_count() > max_nodes {
+            return Err(AdjacencyMatrixError::TooManyNodes(
+                self.node_count(),
+                max_nodes,
+            ));
+        }
+
+        let mut matrix = vec![vec![0u8; self.directed_node_count()]; self.directed_node_count()];
+        for edge in self.iter_directed_edges() {
+            let entry = &mut matrix[edge.from().into_usize()][edge.to().into_usize()];
+            *entry = entry.saturating_add(1);
+        }
+        Ok(matrix)
+    }
+}
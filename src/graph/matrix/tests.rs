@@ -0,0 +1,34 @@
+use crate::{
+    graph::{AdjacencyMatrixError, BidirectedAdjacencyArray},
+    test_util::{directed, unit_graph},
+};
+
+#[test]
+fn test_to_adjacency_matrix() {
+    let graph = unit_graph(
+        2,
+        &[
+            (directed(0, true), directed(1, true)),
+            (directed(0, true), directed(1, true)),
+            (directed(1, true), directed(1, false)),
+        ],
+    );
+
+    assert_eq!(
+        graph.to_adjacency_matrix(2),
+        Ok(vec![
+            vec![0, 0, 2, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 2],
+            vec![0, 2, 0, 0],
+        ])
+    );
+    assert_eq!(
+        graph.to_adjacency_matrix(1),
+        Err(AdjacencyMatrixError::TooManyNodes(2, 1))
+    );
+    assert_eq!(
+        BidirectedAdjacencyArray::<u8, (), ()>::empty().to_adjacency_matrix(0),
+        Ok(Vec::new())
+    );
+}
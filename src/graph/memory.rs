@@ -0,0 +1,73 @@
+use alloc::vec::Vec;
+use core::mem;
+
+use tagged_vec::TaggedVec;
+
+use crate::{graph::BidirectedAdjacencyArray, index::GraphIndexInteger};
+
+#[cfg(test)]
+mod tests;
+
+/// The length and capacity of one of the internal arrays of a graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrayCapacity {
+    pub len: usize,
+    pub capacity: usize,
+}
+
+/// The lengths and capacities of the internal arrays of a graph, as returned by [`BidirectedAdjacencyArray::capacity_report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityReport {
+    /// The edge list offsets of the directed nodes, including the sentinel.
+    pub node_array: ArrayCapacity,
+    /// The targets of the directed edges.
+    pub edge_array: ArrayCapacity,
+    /// The data of the bidirected nodes.
+    pub node_data: ArrayCapacity,
+    /// The keys mapping directed edges to their data.
+    pub edge_data_keys: ArrayCapacity,
+    /// The data of the bidirected edges.
+    pub edge_data: ArrayCapacity,
+}
+
+impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
+    BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    /// Reduces the capacity of all internal arrays to their length.
+    pub fn shrink_to_fit(&mut self) {
+        shrink_tagged_vec_to_fit(&mut self.node_array);
+        shrink_tagged_vec_to_fit(&mut self.edge_array);
+        shrink_tagged_vec_to_fit(&mut self.node_data);
+        shrink_tagged_vec_to_fit(&mut self.edge_data_keys);
+        shrink_tagged_vec_to_fit(&mut self.edge_data);
+    }
+
+    /// Returns the lengths and capacities of all internal arrays.
+    ///
+    /// The capacities are counted in elements, not in bytes.
+    pub fn capacity_report(&self) -> CapacityReport {
+        CapacityReport {
+            node_array: ArrayCapacity::of(&self.node_array),
+            edge_array: ArrayCapacity::of(&self.edge_array),
+            node_data: ArrayCapacity::of(&self.node_data),
+            edge_data_keys: ArrayCapacity::of(&self.edge_data_keys),
+            edge_data: ArrayCapacity::of(&self.edge_data),
+        }
+    }
+}
+
+impl ArrayCapacity {
+    fn of<Index, Value>(array: &TaggedVec<Index, Value>) -> Self {
+        Self {
+            len: array.len(),
+            capacity: array.capacity(),
+        }
+    }
+}
+
+/// [`TaggedVec`] has no `shrink_to_fit`, so this converts it into a [`Vec`] and back, which does not copy the elements.
+fn shrink_tagged_vec_to_fit<Index, Value>(array: &mut TaggedVec<Index, Value>) {
+    let mut vec = Vec::from(mem::take(array));
+    vec.shrink_to_fit();
+    *array = vec.into();
+}
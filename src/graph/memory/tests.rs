@@ -0,0 +1,46 @@
+use tagged_vec::TaggedVec;
+
+use crate::{
+    graph::{ArrayCapacity, BidirectedAdjacencyArray, BidirectedEdge},
+    test_util::directed,
+};
+
+#[test]
+fn test_shrink_to_fit() {
+    let mut nodes = TaggedVec::with_capacity(100);
+    nodes.push(0);
+    nodes.push(1);
+    let edges = vec![
+        BidirectedEdge::new(directed(0, true), directed(1, false), 'a'),
+        BidirectedEdge::new(directed(1, true), directed(1, true), 'b'),
+    ];
+    let mut graph = BidirectedAdjacencyArray::<u8, u32, char>::new(nodes, edges.into());
+
+    let report = graph.capacity_report();
+    assert_eq!(report.node_array.len, 5);
+    assert_eq!(report.edge_array.len, 4);
+    assert_eq!(report.edge_data_keys.len, 4);
+    assert_eq!(report.edge_data.len, 2);
+    assert_eq!(
+        report.node_data,
+        ArrayCapacity {
+            len: 2,
+            capacity: 100
+        }
+    );
+
+    graph.shrink_to_fit();
+    let report = graph.capacity_report();
+    for array in [
+        report.node_array,
+        report.edge_array,
+        report.node_data,
+        report.edge_data_keys,
+        report.edge_data,
+    ] {
+        assert_eq!(array.len, array.capacity);
+    }
+    assert_eq!(report.node_data.len, 2);
+    assert_eq!(*graph.node_data(1.into()), 1);
+    assert_eq!(*graph.edge(1.into()).data(), 'b');
+}
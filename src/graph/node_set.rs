@@ -0,0 +1,112 @@
+use core::marker::PhantomData;
+
+use bitvec::vec::BitVec;
+
+use crate::index::{DirectedNodeIndex, GraphIndexInteger, NodeIndex};
+
+#[cfg(test)]
+mod tests;
+
+/// A set of bidirected nodes, stored as a bitvector with one bit per node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeSet<IndexType> {
+    bits: BitVec,
+    index_type: PhantomData<IndexType>,
+}
+
+/// A set of directed nodes, stored as a bitvector with one bit per directed node.
+///
+/// Each bidirected node has two directed nodes, so the bitvector has twice as many bits as there are bidirected nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectedNodeSet<IndexType> {
+    bits: BitVec,
+    index_type: PhantomData<IndexType>,
+}
+
+impl<IndexType: GraphIndexInteger> NodeSet<IndexType> {
+    /// Creates an empty set for a graph with the given number of bidirected nodes.
+    pub fn new(node_count: usize) -> Self {
+        Self {
+            bits: BitVec::repeat(false, node_count),
+            index_type: PhantomData,
+        }
+    }
+
+    /// Inserts the given node, returning `true` if it was not yet contained.
+    pub fn insert(&mut self, node: NodeIndex<IndexType>) -> bool {
+        !self.bits.replace(node.into_usize(), true)
+    }
+
+    /// Removes the given node, returning `true` if it was contained.
+    pub fn remove(&mut self, node: NodeIndex<IndexType>) -> bool {
+        self.bits.replace(node.into_usize(), false)
+    }
+
+    pub fn contains(&self, node: NodeIndex<IndexType>) -> bool {
+        self.bits[node.into_usize()]
+    }
+
+    /// Returns the number of nodes in the set.
+    ///
+    /// This takes linear time in the number of nodes of the graph.
+    pub fn len(&self) -> usize {
+        self.bits.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.not_any()
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.fill(false);
+    }
+
+    /// Iterates over the nodes in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = NodeIndex<IndexType>> {
+        self.bits.iter_ones().map(NodeIndex::from_usize)
+    }
+}
+
+impl<IndexType: GraphIndexInteger> DirectedNodeSet<IndexType> {
+    /// Creates an empty set for a graph with the given number of bidirected nodes.
+    pub fn new(node_count: usize) -> Self {
+        Self {
+            bits: BitVec::repeat(false, node_count * 2),
+            index_type: PhantomData,
+        }
+    }
+
+    /// Inserts the given directed node, returning `true` if it was not yet contained.
+    pub fn insert(&mut self, node: DirectedNodeIndex<IndexType>) -> bool {
+        !self.bits.replace(node.into_usize(), true)
+    }
+
+    /// Removes the given directed node, returning `true` if it was contained.
+    pub fn remove(&mut self, node: DirectedNodeIndex<IndexType>) -> bool {
+        self.bits.replace(node.into_usize(), false)
+    }
+
+    pub fn contains(&self, node: DirectedNodeIndex<IndexType>) -> bool {
+        self.bits[node.into_usize()]
+    }
+
+    /// Returns the number of directed nodes in the set.
+    ///
+    /// This takes linear time in the number of nodes of the graph.
+    pub fn len(&self) -> usize {
+        self.bits.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.not_any()
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.fill(false);
+    }
+
+    /// Iterates over the directed nodes in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = DirectedNodeIndex<IndexType>> {
+        self.bits.iter_ones().map(DirectedNodeIndex::from_usize)
+    }
+}
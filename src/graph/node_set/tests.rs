@@ -0,0 +1,42 @@
+use crate::{
+    graph::{DirectedNodeSet, NodeSet},
+    index::{DirectedNodeIndex, NodeIndex},
+};
+
+#[test]
+fn test_node_set() {
+    let mut set = NodeSet::<u8>::new(5);
+    assert!(set.is_empty());
+
+    assert!(set.insert(3.into()));
+    assert!(set.insert(1.into()));
+    assert!(!set.insert(3.into()));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(1.into()));
+    assert!(!set.contains(2.into()));
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        vec![NodeIndex::from(1), NodeIndex::from(3)]
+    );
+
+    assert!(set.remove(1.into()));
+    assert!(!set.remove(1.into()));
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![NodeIndex::from(3)]);
+}
+
+#[test]
+fn test_directed_node_set() {
+    let mut set = DirectedNodeSet::<u8>::new(3);
+
+    let reverse = DirectedNodeIndex::from_bidirected(2.into(), false);
+    assert!(set.insert(reverse));
+    assert!(!set.contains(reverse.invert()));
+    assert!(set.insert(DirectedNodeIndex::from_bidirected(0.into(), true)));
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        vec![DirectedNodeIndex::from(0), DirectedNodeIndex::from(5)]
+    );
+
+    set.clear();
+    assert!(set.is_empty());
+}
@@ -0,0 +1,43 @@
+use thiserror::Error;
+
+use crate::{
+    data::{GfaEdgeData, GfaNodeData},
+    graph::BidirectedAdjacencyArray,
+    index::{EdgeIndex, GraphIndexInteger, NodeIndex},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum OverlapError<IndexType> {
+    #[error("the overlap of {2} of edge {0} is longer than the sequence of length {3} of node {1}")]
+    OverlapTooLong(EdgeIndex<IndexType>, NodeIndex<IndexType>, u16, usize),
+}
+
+impl<IndexType: GraphIndexInteger, NodeData: GfaNodeData, EdgeData: GfaEdgeData>
+    BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    /// Checks that the overlap of each edge is at most as long as the sequences of both its endpoints.
+    ///
+    /// Nodes with unknown sequence length are not checked.
+    /// Returns an error for the first edge whose overlap is too long, in order of the edge indices.
+    pub fn validate_overlaps(&self) -> Result<(), OverlapError<IndexType>> {
+        for edge in self.iter_edges() {
+            let edge_view = self.edge(edge);
+            let overlap = edge_view.data().overlap();
+            for node in [
+                edge_view.from().into_bidirected(),
+                edge_view.to().into_bidirected(),
+            ] {
+                if let Some(length) = self.node_data(node).sequence_length()
+                    && usize::from(overlap) > length
+                {
+                    return Err(OverlapError::OverlapTooLong(edge, node, overlap, length));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
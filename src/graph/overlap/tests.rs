@@ -0,0 +1,42 @@
+use crate::{
+    graph::{BidirectedAdjacencyArray, OverlapError},
+    index::DirectedNodeIndex,
+    io::gfa1::{PlainGfaEdgeData, PlainGfaNodeData},
+    test_util::{directed, gfa_graph},
+};
+
+fn example_graph(
+    edges: &[(DirectedNodeIndex<u16>, DirectedNodeIndex<u16>, u16)],
+) -> BidirectedAdjacencyArray<u16, PlainGfaNodeData, PlainGfaEdgeData> {
+    let nodes = vec![
+        PlainGfaNodeData::new("N0", "AACCG"),
+        PlainGfaNodeData::new("N1", "CGT"),
+        PlainGfaNodeData::new_without_sequence("N2"),
+    ];
+    gfa_graph(nodes, edges)
+}
+
+#[test]
+fn test_validate_overlaps() {
+    let graph = example_graph(&[
+        (directed(0, true), directed(1, true), 3),
+        (directed(0, true), directed(2, false), 5),
+        (directed(2, true), directed(2, true), 100),
+    ]);
+    assert_eq!(graph.validate_overlaps(), Ok(()));
+
+    let graph = example_graph(&[
+        (directed(0, true), directed(1, true), 3),
+        (directed(1, false), directed(0, false), 4),
+    ]);
+    assert_eq!(
+        graph.validate_overlaps(),
+        Err(OverlapError::OverlapTooLong(1.into(), 1.into(), 4, 3)),
+    );
+
+    let graph = example_graph(&[(directed(2, true), directed(0, true), 6)]);
+    assert_eq!(
+        graph.validate_overlaps(),
+        Err(OverlapError::OverlapTooLong(0.into(), 0.into(), 6, 5)),
+    );
+}
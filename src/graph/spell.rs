@@ -0,0 +1,78 @@
+use alloc::{borrow::Cow, string::String};
+
+use thiserror::Error;
+
+use crate::{
+    data::{GfaEdgeData, GfaNodeData},
+    graph::BidirectedAdjacencyArray,
+    index::{DirectedNodeIndex, GraphIndexInteger, NodeIndex},
+    io::sequence::reverse_complement,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SpellError<IndexType> {
+    #[error("directed node {0} has no edge to directed node {1}")]
+    NotAdjacent(DirectedNodeIndex<IndexType>, DirectedNodeIndex<IndexType>),
+
+    #[error("node {0} has no sequence")]
+    MissingSequence(NodeIndex<IndexType>),
+
+    #[error("the overlap of {1} into directed node {0} is longer than its sequence")]
+    OverlapTooLong(DirectedNodeIndex<IndexType>, u16),
+}
+
+impl<IndexType: GraphIndexInteger, NodeData: GfaNodeData, EdgeData: GfaEdgeData>
+    BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    /// Spells the sequence of the given walk of directed nodes.
+    ///
+    /// Reverse directed nodes contribute the reverse complement of their sequence.
+    /// For each pair of consecutive nodes, the overlap of the connecting edge is removed from the start of the second node.
+    /// If there are multiple connecting edges, the first one in the outgoing edge list of the first node is used.
+    ///
+    /// An empty path spells the empty string.
+    pub fn spell_path(
+        &self,
+        path: &[DirectedNodeIndex<IndexType>],
+    ) -> Result<String, SpellError<IndexType>> {
+        let Some(&first) = path.first() else {
+            return Ok(String::new());
+        };
+
+        let mut result = self.directed_sequence(first)?.into_owned();
+        for window in path.windows(2) {
+            let (from, to) = (window[0], window[1]);
+            let overlap = self
+                .iter_successors_with_overlap(from)
+                .find_map(|(successor, overlap)| (successor == to).then_some(overlap))
+                .ok_or(SpellError::NotAdjacent(from, to))?;
+
+            let sequence = self.directed_sequence(to)?;
+            let suffix = sequence
+                .get(usize::from(overlap)..)
+                .ok_or(SpellError::OverlapTooLong(to, overlap))?;
+            result.push_str(suffix);
+        }
+
+        Ok(result)
+    }
+
+    fn directed_sequence(
+        &self,
+        node: DirectedNodeIndex<IndexType>,
+    ) -> Result<Cow<'_, str>, SpellError<IndexType>> {
+        let sequence = self
+            .node_data(node.into_bidirected())
+            .sequence()
+            .ok_or(SpellError::MissingSequence(node.into_bidirected()))?;
+
+        if node.is_forward() {
+            Ok(sequence)
+        } else {
+            Ok(Cow::Owned(reverse_complement(&sequence)))
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    graph::{BidirectedAdjacencyArray, spell::SpellError},
+    io::gfa1::{PlainGfaEdgeData, PlainGfaNodeData},
+    test_util::{directed, gfa_graph},
+};
+
+fn example_graph() -> BidirectedAdjacencyArray<u16, PlainGfaNodeData, PlainGfaEdgeData> {
+    let nodes = vec![
+        PlainGfaNodeData::new("N0", "AACCG"),
+        PlainGfaNodeData::new("N1", "CGTTA"),
+        PlainGfaNodeData::new("N2", "TAAGG"),
+        PlainGfaNodeData::new_without_sequence("N3"),
+    ];
+    gfa_graph(
+        nodes,
+        &[
+            (directed(0, true), directed(1, true), 2),
+            (directed(1, true), directed(2, false), 0),
+            (directed(0, true), directed(3, true), 0),
+            (directed(1, true), directed(1, true), 9),
+        ],
+    )
+}
+
+#[test]
+fn test_spell_path() {
+    let graph = example_graph();
+
+    assert_eq!(graph.spell_path(&[]), Ok(String::new()));
+    assert_eq!(
+        graph.spell_path(&[directed(2, false)]),
+        Ok("CCTTA".to_string())
+    );
+    assert_eq!(
+        graph.spell_path(&[directed(0, true), directed(1, true)]),
+        Ok("AACCGTTA".to_string())
+    );
+    assert_eq!(
+        graph.spell_path(&[directed(0, true), directed(1, true), directed(2, false)]),
+        Ok("AACCGTTACCTTA".to_string())
+    );
+
+    // The reverse complement walk spells the reverse complement sequence.
+    assert_eq!(
+        graph.spell_path(&[directed(2, true), directed(1, false), directed(0, false)]),
+        Ok("TAAGGTAACGGTT".to_string())
+    );
+}
+
+#[test]
+fn test_spell_path_errors() {
+    let graph = example_graph();
+
+    assert_eq!(
+        graph.spell_path(&[directed(0, true), directed(2, true)]),
+        Err(SpellError::NotAdjacent(
+            directed(0, true),
+            directed(2, true)
+        ))
+    );
+    assert_eq!(
+        graph.spell_path(&[directed(0, true), directed(3, true)]),
+        Err(SpellError::MissingSequence(3.into()))
+    );
+    assert_eq!(
+        graph.spell_path(&[directed(1, true), directed(1, true)]),
+        Err(SpellError::OverlapTooLong(directed(1, true), 9))
+    );
+}
@@ -0,0 +1,262 @@
+//! Summary statistics of graphs.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{cmp::Reverse, fmt};
+
+use crate::{
+    data::GfaNodeData,
+    graph::BidirectedAdjacencyArray,
+    index::{DirectedNodeIndex, GraphIndexInteger, NodeIndex},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A summary of the most common statistics of a graph, as computed by [`BidirectedAdjacencyArray::compute_statistics`].
+///
+/// The [`Display`](fmt::Display) implementation prints one statistic per line as `name: value`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraphStatistics {
+    pub node_count: usize,
+    pub edge_count: usize,
+    /// The number of edges that connect a node to itself, see [`BidirectedAdjacencyArray::self_loop_kind`].
+    pub self_loop_count: usize,
+    /// See [`BidirectedAdjacencyArray::total_sequence_length`].
+    pub total_sequence_length: u64,
+    /// See [`BidirectedAdjacencyArray::n50`].
+    pub n50: usize,
+    /// See [`BidirectedAdjacencyArray::count_weakly_connected_components`].
+    pub weakly_connected_component_count: usize,
+    /// The smallest [degree](BidirectedAdjacencyArray::degree) of a node, or zero if the graph has no nodes.
+    pub min_degree: usize,
+    /// The mean [degree](BidirectedAdjacencyArray::degree) of the nodes, or zero if the graph has no nodes.
+    pub mean_degree: f64,
+    /// The largest [degree](BidirectedAdjacencyArray::degree) of a node, or zero if the graph has no nodes.
+    pub max_degree: usize,
+}
+
+impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
+    BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    /// Returns the sum of the sequence lengths of all nodes.
+    ///
+    /// Nodes whose sequence length is unknown are counted as length zero.
+    pub fn total_sequence_length(&self) -> u64
+    where
+        NodeData: GfaNodeData,
+    {
+        self.iter_node_data()
+            .map(|(_, node_data)| node_data.sequence_length().unwrap_or(0) as u64)
+            .sum()
+    }
+
+    /// Returns a map from sequence length to the number of nodes with that length.
+    ///
+    /// Nodes whose sequence length is unknown are skipped.
+    pub fn node_length_histogram(&self) -> BTreeMap<usize, usize>
+    where
+        NodeData: GfaNodeData,
+    {
+        let mut histogram = BTreeMap::new();
+        for (_, node_data) in self.iter_node_data() {
+            if let Some(length) = node_data.sequence_length() {
+                *histogram.entry(length).or_default() += 1;
+            }
+        }
+        histogram
+    }
+
+    /// Returns the N50 of the sequence lengths of the nodes.
+    ///
+    /// This is the largest length `l` such that the nodes of length at least `l` make up at least half of the [total sequence length](Self::total_sequence_length).
+    /// Nodes whose sequence length is unknown are skipped, and a graph without sequence has an N50 of zero.
+    pub fn n50(&self) -> usize
+    where
+        NodeData: GfaNodeData,
+    {
+        self.length_at_half(self.total_sequence_length())
+            .unwrap_or(0)
+    }
+
+    /// Returns the NG50 of the sequence lengths of the nodes.
+    ///
+    /// This is like [`Self::n50`], but with respect to half of the given genome size instead of half of the total sequence length.
+    /// Returns `None` if the total sequence length is less than half of the genome size.
+    pub fn ng50(&self, genome_size: u64) -> Option<usize>
+    where
+        NodeData: GfaNodeData,
+    {
+        self.length_at_half(genome_size)
+    }
+
+    /// Returns the largest length such that the nodes of at least that length sum up to at least half of `total`.
+    fn length_at_half(&self, total: u64) -> Option<usize>
+    where
+        NodeData: GfaNodeData,
+    {
+        let mut lengths = self
+            .iter_node_data()
+            .filter_map(|(_, node_data)| node_data.sequence_length())
+            .collect::<Vec<_>>();
+        lengths.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut sum = 0;
+        lengths.into_iter().find(|&length| {
+            sum += length as u64;
+            2 * sum >= total
+        })
+    }
+
+    /// Computes a summary of the most common statistics of the graph.
+    pub fn compute_statistics(&self) -> GraphStatistics
+    where
+        NodeData: GfaNodeData,
+    {
+        let mut min_degree = usize::MAX;
+        let mut max_degree = 0;
+        let mut degree_sum = 0;
+        for node in self.iter_nodes() {
+            let degree = self.degree(node);
+            min_degree = min_degree.min(degree);
+            max_degree = max_degree.max(degree);
+            degree_sum += degree;
+        }
+        let (min_degree, mean_degree) = if self.node_count() == 0 {
+            (0, 0.0)
+        } else {
+            (min_degree, degree_sum as f64 / self.node_count() as f64)
+        };
+
+        GraphStatistics {
+            node_count: self.node_count(),
+            edge_count: self.edge_count(),
+            self_loop_count: self
+                .iter_edges()
+                .filter(|&edge| self.self_loop_kind(edge).is_some())
+                .count(),
+            total_sequence_length: self.total_sequence_length(),
+            n50: self.n50(),
+            weakly_connected_component_count: self.count_weakly_connected_components(),
+            min_degree,
+            mean_degree,
+            max_degree,
+        }
+    }
+
+    /// Returns a map from degree to the number of nodes with that degree.
+    ///
+    /// The degree of a node is as returned by [`Self::degree`].
+    pub fn degree_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for node in self.iter_nodes() {
+            *histogram.entry(self.degree(node)).or_default() += 1;
+        }
+        histogram
+    }
+
+    /// Returns all nodes with their degree, sorted by descending degree.
+    ///
+    /// The degree of a node is as returned by [`Self::degree`].
+    /// Nodes with the same degree are sorted by ascending index.
+    pub fn nodes_by_degree_desc(&self) -> Vec<(NodeIndex<IndexType>, usize)> {
+        let mut nodes = self.nodes_with_degree();
+        nodes.sort_by_key(|&(_, degree)| Reverse(degree));
+        nodes
+    }
+
+    /// Returns the `k` nodes with the highest degree, in the same order as [`Self::nodes_by_degree_desc`].
+    ///
+    /// If there are fewer than `k` nodes, then all nodes are returned.
+    /// Only the returned nodes are sorted, so this is faster than [`Self::nodes_by_degree_desc`] for small `k`.
+    pub fn top_k_by_degree(&self, k: usize) -> Vec<(NodeIndex<IndexType>, usize)> {
+        let mut nodes = self.nodes_with_degree();
+        let key = |&(node, degree): &(NodeIndex<IndexType>, usize)| (Reverse(degree), node);
+        if k < nodes.len() {
+            nodes.select_nth_unstable_by_key(k, key);
+            nodes.truncate(k);
+        }
+        nodes.sort_unstable_by_key(key);
+        nodes
+    }
+
+    fn nodes_with_degree(&self) -> Vec<(NodeIndex<IndexType>, usize)> {
+        self.iter_nodes()
+            .map(|node| (node, self.degree(node)))
+            .collect()
+    }
+
+    /// Returns the ratio of the number of edges to the maximum number of edges without parallel edges.
+    ///
+    /// Between two distinct nodes there are four possible edges, one for each combination of sides.
+    /// Each node has three possible self loops, since `(n+, n+)` and `(n-, n-)` are the same edge, while `(n+, n-)` and `(n-, n+)` are distinct.
+    /// Hence, the maximum number of edges for `n` nodes is `4 * n * (n - 1) / 2 + 3 * n = n * (2 * n + 1)`.
+    ///
+    /// Graphs with parallel edges may have a density greater than one.
+    /// The density of a graph without nodes is zero.
+    pub fn density(&self) -> f64 {
+        let node_count = self.node_count() as f64;
+        let max_edge_count = node_count * (2.0 * node_count + 1.0);
+        if max_edge_count == 0.0 {
+            0.0
+        } else {
+            self.edge_count() as f64 / max_edge_count
+        }
+    }
+
+    /// Counts the directed 3-cycles `a -> b -> c -> a` over three distinct directed nodes.
+    ///
+    /// Each cycle is counted once, regardless of the node it starts from, and parallel edges do not count extra.
+    ///
+    /// Every directed 3-cycle `a -> b -> c -> a` has the distinct reverse-complemental copy `\hat{a} -> \hat{c} -> \hat{b} -> \hat{a}`,
+    /// since no directed node is its own inverse.
+    /// Hence, each triangle of the bidirected graph is counted twice, and the result is always even.
+    pub fn count_triangles(&self) -> usize {
+        let successors = self
+            .iter_directed_nodes()
+            .map(|node| {
+                let mut successors = self
+                    .iter_outgoing_edges(node)
+                    .map(|edge| edge.to())
+                    .collect::<Vec<_>>();
+                successors.sort_unstable();
+                successors.dedup();
+                successors
+            })
+            .collect::<Vec<_>>();
+
+        // Count each cycle only from its smallest directed node.
+        let mut count = 0;
+        for (a, a_successors) in successors.iter().enumerate() {
+            let a = DirectedNodeIndex::from_usize(a);
+            for &b in a_successors.iter().filter(|&&b| b > a) {
+                for &c in successors[b.into_usize()]
+                    .iter()
+                    .filter(|&&c| c > a && c != b)
+                {
+                    if successors[c.into_usize()].binary_search(&a).is_ok() {
+                        count += 1;
+                    }
+                }
+            }
+        }
+        count
+    }
+}
+
+impl fmt::Display for GraphStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "nodes: {}", self.node_count)?;
+        writeln!(f, "edges: {}", self.edge_count)?;
+        writeln!(f, "self loops: {}", self.self_loop_count)?;
+        writeln!(f, "total sequence length: {}", self.total_sequence_length)?;
+        writeln!(f, "N50: {}", self.n50)?;
+        writeln!(
+            f,
+            "weakly connected components: {}",
+            self.weakly_connected_component_count
+        )?;
+        writeln!(f, "min degree: {}", self.min_degree)?;
+        writeln!(f, "mean degree: {:.2}", self.mean_degree)?;
+        write!(f, "max degree: {}", self.max_degree)
+    }
+}
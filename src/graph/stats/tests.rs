@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge, stats::GraphStatistics},
+    index::DirectedNodeIndex,
+    io::gfa1::{PlainGfaEdgeData, PlainGfaNodeData},
+    test_util::{directed, gfa_graph},
+};
+
+fn example_graph() -> BidirectedAdjacencyArray<u16, PlainGfaNodeData, PlainGfaEdgeData> {
+    let nodes = vec![
+        PlainGfaNodeData::new("N0", "ACGT"),
+        PlainGfaNodeData::new("N1", "AC"),
+        PlainGfaNodeData::new_with_length("N2", 4),
+        PlainGfaNodeData::new_without_sequence("N3"),
+    ];
+    gfa_graph(
+        nodes,
+        &[
+            (directed(0, true), directed(1, true), 0),
+            (directed(1, true), directed(2, false), 0),
+            (directed(1, false), directed(1, true), 0),
+        ],
+    )
+}
+
+#[test]
+fn test_sequence_length_statistics() {
+    let graph = example_graph();
+
+    assert_eq!(graph.total_sequence_length(), 10);
+    assert_eq!(
+        graph.node_length_histogram(),
+        BTreeMap::from([(2, 1), (4, 2)])
+    );
+}
+
+#[test]
+fn test_degree_histogram() {
+    let graph = example_graph();
+
+    assert_eq!(
+        graph.degree_histogram(),
+        BTreeMap::from([(0, 1), (1, 2), (3, 1)])
+    );
+}
+
+#[test]
+fn test_nodes_by_degree() {
+    let graph = example_graph();
+    let expected = vec![(1.into(), 3), (0.into(), 1), (2.into(), 1), (3.into(), 0)];
+
+    assert_eq!(graph.degree(1.into()), 3);
+    assert_eq!(graph.nodes_by_degree_desc(), expected);
+    assert_eq!(graph.top_k_by_degree(2), expected[..2]);
+    assert_eq!(graph.top_k_by_degree(3), expected[..3]);
+    assert_eq!(graph.top_k_by_degree(0), []);
+    assert_eq!(graph.top_k_by_degree(10), expected);
+}
+
+#[test]
+fn test_compute_statistics() {
+    let statistics = example_graph().compute_statistics();
+    assert_eq!(
+        statistics,
+        GraphStatistics {
+            node_count: 4,
+            edge_count: 3,
+            self_loop_count: 1,
+            total_sequence_length: 10,
+            n50: 4,
+            weakly_connected_component_count: 2,
+            min_degree: 0,
+            mean_degree: 1.25,
+            max_degree: 3,
+        }
+    );
+    assert_eq!(
+        statistics.to_string(),
+        "nodes: 4\nedges: 3\nself loops: 1\ntotal sequence length: 10\nN50: 4\n\
+         weakly connected components: 2\nmin degree: 0\nmean degree: 1.25\nmax degree: 3"
+    );
+
+    let statistics = BidirectedAdjacencyArray::<u16, PlainGfaNodeData, PlainGfaEdgeData>::empty()
+        .compute_statistics();
+    assert_eq!(statistics.node_count, 0);
+    assert_eq!(statistics.min_degree, 0);
+    assert_eq!(statistics.mean_degree, 0.0);
+    assert_eq!(statistics.max_degree, 0);
+}
+
+#[test]
+fn test_n50() {
+    let graph = example_graph();
+    assert_eq!(graph.n50(), 4);
+    assert_eq!(graph.ng50(16), Some(4));
+    assert_eq!(graph.ng50(20), Some(2));
+    assert_eq!(graph.ng50(21), None);
+
+    let nodes = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]
+        .into_iter()
+        .map(|length| PlainGfaNodeData::new_with_length(format!("N{length}"), length))
+        .collect::<Vec<_>>();
+    let graph =
+        BidirectedAdjacencyArray::<u16, _, PlainGfaEdgeData>::new(nodes.into(), Vec::new().into());
+    // The total is 55, and 10 + 9 + 8 = 27 < 27.5 <= 34 = 10 + 9 + 8 + 7.
+    assert_eq!(graph.n50(), 7);
+    assert_eq!(graph.ng50(80), Some(6));
+    assert_eq!(graph.ng50(100), Some(3));
+
+    let graph = BidirectedAdjacencyArray::<u16, PlainGfaNodeData, PlainGfaEdgeData>::new(
+        Vec::new().into(),
+        Vec::new().into(),
+    );
+    assert_eq!(graph.n50(), 0);
+    assert_eq!(graph.ng50(1), None);
+}
+
+#[test]
+fn test_count_triangles() {
+    let triangle = |edges: Vec<(DirectedNodeIndex<u16>, DirectedNodeIndex<u16>)>| {
+        BidirectedAdjacencyArray::<u16, (), ()>::new(
+            vec![(); 4].into(),
+            edges
+                .into_iter()
+                .map(|(from, to)| BidirectedEdge::new(from, to, ()))
+                .collect(),
+        )
+    };
+
+    // A bidirected triangle yields a directed 3-cycle over each copy of its nodes.
+    let graph = triangle(vec![
+        (directed(0, true), directed(1, true)),
+        (directed(1, true), directed(2, true)),
+        (directed(2, true), directed(0, true)),
+    ]);
+    assert_eq!(graph.count_triangles(), 2);
+
+    // Parallel edges and a fourth node do not add cycles.
+    let graph = triangle(vec![
+        (directed(0, true), directed(1, true)),
+        (directed(0, true), directed(1, true)),
+        (directed(1, true), directed(2, false)),
+        (directed(2, false), directed(0, true)),
+        (directed(2, false), directed(3, true)),
+    ]);
+    assert_eq!(graph.count_triangles(), 2);
+
+    // A path that cannot be closed because the orientations do not match.
+    let graph = triangle(vec![
+        (directed(0, true), directed(1, true)),
+        (directed(1, true), directed(2, true)),
+        (directed(2, true), directed(0, false)),
+    ]);
+    assert_eq!(graph.count_triangles(), 0);
+
+    let graph = triangle(vec![]);
+    assert_eq!(graph.count_triangles(), 0);
+}
+
+#[test]
+fn test_density() {
+    let complete_graph = |node_count: usize| {
+        let mut edges = Vec::new();
+        for from in 0..2 * node_count {
+            for to in 0..2 * node_count {
+                let edge = BidirectedEdge::new(
+                    directed(from / 2, from % 2 == 0),
+                    directed(to / 2, to % 2 == 0),
+                    (),
+                )
+                .canonical();
+                if !edges
+                    .iter()
+                    .any(|other: &BidirectedEdge<_, _>| other.structural_eq(&edge))
+                {
+                    edges.push(edge);
+                }
+            }
+        }
+        BidirectedAdjacencyArray::<u16, (), ()>::new(vec![(); node_count].into(), edges.into())
+    };
+
+    assert_eq!(complete_graph(0).density(), 0.0);
+    for node_count in 1..5 {
+        let graph = complete_graph(node_count);
+        assert_eq!(graph.edge_count(), node_count * (2 * node_count + 1));
+        assert_eq!(graph.density(), 1.0);
+    }
+
+    let graph = BidirectedAdjacencyArray::<u16, (), ()>::new(vec![(); 2].into(), Vec::new().into());
+    assert_eq!(graph.density(), 0.0);
+    let graph = BidirectedAdjacencyArray::<u16, (), ()>::new(
+        vec![(); 2].into(),
+        vec![BidirectedEdge::new(
+            directed(0, true),
+            directed(1, false),
+            (),
+        )]
+        .into(),
+    );
+    assert_eq!(graph.density(), 0.1);
+}
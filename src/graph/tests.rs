@@ -1,12 +1,32 @@
+use std::hash::{DefaultHasher, Hasher};
+
 use tagged_vec::TaggedVec;
 
-use crate::graph::{BidirectedAdjacencyArray, BidirectedEdge};
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge, SelfLoopKind},
+    index::{DirectedNodeIndex, EdgeIndex, NodeIndex, ParseIndexError},
+};
 
 #[test]
 fn test_empty_construction() {
     let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(TaggedVec::new(), TaggedVec::new());
     assert_eq!(graph.node_count(), 0);
     assert_eq!(graph.edge_count(), 0);
+    assert_eq!(graph.directed_node_count(), 0);
+    assert_eq!(graph.directed_edge_count(), 0);
+}
+
+#[test]
+fn test_default_construction() {
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::default();
+    assert_eq!(graph.node_count(), 0);
+    assert_eq!(graph.edge_count(), 0);
+    assert_eq!(graph.csr_parts().0.len(), 1);
+    assert_eq!(graph.iter_directed_edges().count(), 0);
+
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::empty();
+    assert_eq!(graph.directed_node_count(), 0);
+    assert_eq!(graph.directed_edge_count(), 0);
 }
 
 #[test]
@@ -84,3 +104,818 @@ fn test_path_construction() {
         vec![(3.into())]
     );
 }
+
+#[test]
+fn test_iter_edge_views() {
+    let nodes = vec![(), (), ()];
+    let edges = vec![
+        BidirectedEdge {
+            from: 0.into(),
+            from_forward: true,
+            to: 1.into(),
+            to_forward: false,
+            data: 10,
+        },
+        BidirectedEdge {
+            from: 2.into(),
+            from_forward: false,
+            to: 2.into(),
+            to_forward: false,
+            data: 20,
+        },
+    ];
+    let graph = BidirectedAdjacencyArray::<u8, (), u8>::new(nodes.into(), edges.into());
+
+    let views = graph
+        .iter_edge_views()
+        .map(|edge| (edge.from(), edge.to(), *edge.data()))
+        .collect::<Vec<_>>();
+    let expected = graph
+        .iter_edges()
+        .map(|edge| {
+            let edge = graph.edge(edge);
+            (edge.from(), edge.to(), *edge.data())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(views, expected);
+    assert_eq!(views[0], (0.into(), 3.into(), 10));
+    assert_eq!(graph.iter_node_data().count(), 3);
+
+    let selected = graph
+        .edges(&[1.into(), 0.into(), 1.into()])
+        .map(|edge| (edge.from(), edge.to(), *edge.data()))
+        .collect::<Vec<_>>();
+    assert_eq!(selected, vec![views[1], views[0], views[1]]);
+    assert_eq!(graph.edges(&[]).count(), 0);
+}
+
+#[test]
+fn test_mutable_data_accessors() {
+    let nodes = vec![0, 0];
+    let edges = vec![BidirectedEdge {
+        from: 0.into(),
+        from_forward: true,
+        to: 1.into(),
+        to_forward: false,
+        data: 0,
+    }];
+    let mut graph = BidirectedAdjacencyArray::<u8, u8, u8>::new(nodes.into(), edges.into());
+
+    *graph.node_data_mut(1.into()) = 5;
+    *graph.edge_data_mut(0.into()) = 7;
+
+    assert_eq!(*graph.node_data(1.into()), 5);
+    let edge = graph.edge(0.into());
+    assert_eq!(*edge.data(), 7);
+    assert_eq!(*graph.directed_edge_data(edge.forward()).data(), 7);
+    assert_eq!(*graph.directed_edge_data(edge.reverse()).data(), 7);
+    assert_eq!(*graph.directed_edge_data_ref(edge.forward()), 7);
+    assert_eq!(*graph.directed_edge_data_ref(edge.reverse()), 7);
+    assert!(
+        graph
+            .try_directed_edge_data(edge.forward())
+            .unwrap()
+            .is_forward()
+    );
+    assert!(
+        !graph
+            .try_directed_edge_data(edge.reverse())
+            .unwrap()
+            .is_forward()
+    );
+    assert!(graph.try_directed_edge_data(2.into()).is_none());
+}
+
+#[test]
+fn test_find_edge() {
+    let nodes = vec![(), (), ()];
+    let mut edges = Vec::new();
+    for (from, from_forward, to, to_forward) in [
+        (0, true, 1, false),
+        (1, true, 1, true),
+        (0, false, 0, false),
+        (2, true, 2, false),
+        (2, false, 2, true),
+    ] {
+        edges.push(BidirectedEdge {
+            from: from.into(),
+            from_forward,
+            to: to.into(),
+            to_forward,
+            data: (),
+        });
+    }
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(nodes.into(), edges.clone().into());
+    assert_eq!(graph.directed_node_count(), 6);
+    assert_eq!(graph.directed_edge_count(), 10);
+    assert_eq!(
+        graph.iter_directed_edges().count(),
+        graph.directed_edge_count()
+    );
+    for (expected_index, directed_edge) in graph.iter_directed_edges().enumerate() {
+        assert_eq!(directed_edge.index(), expected_index.into());
+        assert!(
+            graph
+                .iter_outgoing_edges(directed_edge.from())
+                .any(|edge| edge.index() == directed_edge.index())
+        );
+    }
+
+    for (index, edge) in edges.iter().enumerate() {
+        let found = graph
+            .find_edge(edge.from, edge.from_forward, edge.to, edge.to_forward)
+            .unwrap();
+        assert_eq!(found, index.into());
+
+        let view = graph.edge(found);
+        assert_eq!(view.from_bidirected(), (edge.from, edge.from_forward));
+        assert_eq!(view.to_bidirected(), (edge.to, edge.to_forward));
+    }
+
+    // The reverse-complemental orientation denotes the same edge.
+    assert_eq!(
+        graph.find_edge(1.into(), true, 0.into(), false),
+        Some(0.into())
+    );
+    assert!(!graph.contains_edge(0.into(), true, 1.into(), true));
+    assert!(!graph.contains_edge(0.into(), false, 0.into(), true));
+}
+
+#[test]
+fn test_edge_multiplicity() {
+    let nodes = vec![(), (), ()];
+    let mut edges = Vec::new();
+    for (from, from_forward, to, to_forward) in [
+        (0, true, 1, true),
+        (1, false, 0, false),
+        (1, true, 1, false),
+        (1, true, 1, true),
+        (1, true, 2, true),
+    ] {
+        edges.push(BidirectedEdge {
+            from: from.into(),
+            from_forward,
+            to: to.into(),
+            to_forward,
+            data: (),
+        });
+    }
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(nodes.into(), edges.into());
+
+    assert_eq!(graph.edge_multiplicity(0.into(), 1.into()), 2);
+    assert_eq!(graph.edge_multiplicity(1.into(), 0.into()), 2);
+    assert_eq!(graph.edge_multiplicity(1.into(), 1.into()), 2);
+    assert_eq!(graph.edge_multiplicity(1.into(), 2.into()), 1);
+    assert_eq!(graph.edge_multiplicity(0.into(), 0.into()), 0);
+    assert_eq!(graph.edge_multiplicity(0.into(), 2.into()), 0);
+}
+
+#[test]
+fn test_into_edges_roundtrip() {
+    let nodes = vec![0, 1, 2];
+    let mut edges = Vec::new();
+    for (index, (from, from_forward, to, to_forward)) in [
+        (0, true, 1, false),
+        (1, false, 0, true),
+        (1, true, 1, true),
+        (2, true, 2, false),
+        (2, false, 2, true),
+        (2, false, 0, false),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        edges.push(BidirectedEdge {
+            from: from.into(),
+            from_forward,
+            to: to.into(),
+            to_forward,
+            data: index as u8,
+        });
+    }
+    let expected_graph =
+        BidirectedAdjacencyArray::<u8, u8, u8>::new(nodes.clone().into(), edges.clone().into());
+    let graph =
+        BidirectedAdjacencyArray::<u8, u8, u8>::new(nodes.clone().into(), edges.clone().into());
+
+    for (index, edge) in edges.iter().enumerate() {
+        let actual_edge = graph.bidirected_edge(index.into());
+        assert_eq!(actual_edge.from, edge.from);
+        assert_eq!(actual_edge.from_forward, edge.from_forward);
+        assert_eq!(actual_edge.to, edge.to);
+        assert_eq!(actual_edge.to_forward, edge.to_forward);
+        assert_eq!(actual_edge.data, &edge.data);
+    }
+
+    let (actual_nodes, actual_edges) = graph.into_edges();
+    assert_eq!(actual_nodes, nodes.into());
+    assert_eq!(actual_edges, edges.into());
+
+    let actual_graph = BidirectedAdjacencyArray::new(actual_nodes, actual_edges);
+    expected_graph.expect_equal(&actual_graph);
+}
+
+#[test]
+fn test_clone() {
+    let nodes = vec![1, 2, 3];
+    let edges = vec![
+        BidirectedEdge {
+            from: 0.into(),
+            from_forward: true,
+            to: 1.into(),
+            to_forward: false,
+            data: 10,
+        },
+        BidirectedEdge {
+            from: 2.into(),
+            from_forward: false,
+            to: 2.into(),
+            to_forward: true,
+            data: 20,
+        },
+    ];
+    let graph = BidirectedAdjacencyArray::<u8, i32, i32>::new(nodes.into(), edges.into());
+
+    let mut clone = graph.clone();
+    assert!(graph.compare(&clone).is_ok());
+
+    *clone.edge_data_mut(0.into()) = 11;
+    assert!(graph.compare(&clone).is_err());
+    assert_eq!(*graph.edge(0.into()).data(), 10);
+}
+
+#[test]
+fn test_iter_successors_with_overlap() {
+    let nodes = vec![(), (), ()];
+    let edges = vec![
+        BidirectedEdge::new_gfa(0.into(), 2.into(), 5),
+        BidirectedEdge::new_gfa(0.into(), 5.into(), 7),
+        BidirectedEdge::new_gfa(3.into(), 1.into(), 3),
+    ];
+    let graph = BidirectedAdjacencyArray::<u8, (), _>::new(nodes.into(), edges.into());
+
+    let mut successors = graph
+        .iter_successors_with_overlap(0.into())
+        .collect::<Vec<_>>();
+    successors.sort();
+    assert_eq!(
+        successors,
+        vec![(2.into(), 3), (2.into(), 5), (5.into(), 7)]
+    );
+
+    // Reverse directions carry the same overlap.
+    assert_eq!(
+        graph
+            .iter_successors_with_overlap(4.into())
+            .collect::<Vec<_>>(),
+        vec![(1.into(), 7)]
+    );
+    let mut successors = graph
+        .iter_successors_with_overlap(3.into())
+        .collect::<Vec<_>>();
+    successors.sort();
+    assert_eq!(successors, vec![(1.into(), 3), (1.into(), 5)]);
+}
+
+#[test]
+fn test_iter_node_incidences() {
+    let nodes = vec![(), ()];
+    let mut edges = Vec::new();
+    for (from, from_forward, to, to_forward) in [
+        (0, true, 1, true),
+        (1, true, 0, false),
+        (0, true, 0, true),
+        (0, false, 0, true),
+        (0, true, 0, false),
+    ] {
+        edges.push(BidirectedEdge {
+            from: from.into(),
+            from_forward,
+            to: to.into(),
+            to_forward,
+            data: (),
+        });
+    }
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(nodes.into(), edges.into());
+
+    let mut incidences = graph.iter_node_incidences(0.into()).collect::<Vec<_>>();
+    incidences.sort();
+    assert_eq!(
+        incidences,
+        vec![
+            (0.into(), true),
+            (1.into(), true),
+            (2.into(), true),
+            (3.into(), false),
+            (4.into(), true),
+        ]
+    );
+
+    let mut incidences = graph.iter_node_incidences(1.into()).collect::<Vec<_>>();
+    incidences.sort();
+    assert_eq!(incidences, vec![(0.into(), false), (1.into(), true)]);
+}
+
+#[test]
+fn test_directed_edge_into_bidirected_oriented() {
+    let nodes = vec![(), ()];
+    let mut edges = Vec::new();
+    for (from, from_forward, to, to_forward) in
+        [(0, true, 1, false), (1, true, 1, true), (0, false, 0, true)]
+    {
+        edges.push(BidirectedEdge {
+            from: from.into(),
+            from_forward,
+            to: to.into(),
+            to_forward,
+            data: (),
+        });
+    }
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(nodes.into(), edges.into());
+
+    for edge in graph.iter_edge_views() {
+        let index = graph.directed_edge_into_bidirected(edge.forward());
+        assert_eq!(
+            graph.directed_edges(index),
+            (edge.forward(), edge.reverse())
+        );
+        assert_eq!(
+            graph.directed_edge_into_bidirected_oriented(edge.forward()),
+            (index, true)
+        );
+        assert_eq!(
+            graph.directed_edge_into_bidirected_oriented(edge.reverse()),
+            (index, false)
+        );
+    }
+
+    for directed_edge in graph.iter_directed_edges() {
+        let (edge, is_forward) =
+            graph.directed_edge_into_bidirected_oriented(directed_edge.index());
+        assert_eq!(
+            is_forward,
+            graph.directed_edge_data(directed_edge.index()).is_forward()
+        );
+        assert_eq!(edge, graph.directed_edge_data(directed_edge.index()).edge());
+    }
+}
+
+#[test]
+fn test_inverse_directed_edge() {
+    let nodes = vec![(), ()];
+    let edges = vec![
+        BidirectedEdge::new(
+            DirectedNodeIndex::from_bidirected(0.into(), true),
+            DirectedNodeIndex::from_bidirected(1.into(), false),
+            (),
+        ),
+        BidirectedEdge::new(
+            DirectedNodeIndex::from_bidirected(1.into(), true),
+            DirectedNodeIndex::from_bidirected(1.into(), true),
+            (),
+        ),
+        BidirectedEdge::new(
+            DirectedNodeIndex::from_bidirected(0.into(), true),
+            DirectedNodeIndex::from_bidirected(0.into(), false),
+            (),
+        ),
+    ];
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(nodes.into(), edges.into());
+
+    for edge in graph.iter_edge_views() {
+        assert_eq!(graph.inverse_directed_edge(edge.forward()), edge.reverse());
+        assert_eq!(graph.inverse_directed_edge(edge.reverse()), edge.forward());
+    }
+
+    let directed_edges = graph.iter_directed_edges().collect::<Vec<_>>();
+    for directed_edge in &directed_edges {
+        let inverse = graph.inverse_directed_edge(directed_edge.index());
+        assert_ne!(inverse, directed_edge.index());
+        assert_eq!(graph.inverse_directed_edge(inverse), directed_edge.index());
+
+        let inverse = directed_edges
+            .iter()
+            .find(|other| other.index() == inverse)
+            .unwrap();
+        assert_eq!(inverse.from(), directed_edge.to().invert());
+        assert_eq!(inverse.to(), directed_edge.from().invert());
+    }
+}
+
+#[test]
+fn test_csr_parts() {
+    let nodes = vec![(), (), ()];
+    let edges = vec![
+        BidirectedEdge {
+            from: 0.into(),
+            from_forward: true,
+            to: 1.into(),
+            to_forward: true,
+            data: (),
+        },
+        BidirectedEdge {
+            from: 2.into(),
+            from_forward: false,
+            to: 0.into(),
+            to_forward: true,
+            data: (),
+        },
+    ];
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(nodes.into(), edges.into());
+
+    let (offsets, targets) = graph.csr_parts();
+    assert_eq!(offsets.len(), graph.directed_node_count() + 1);
+    assert_eq!(targets.len(), graph.directed_edge_count());
+    assert_eq!(offsets.last().unwrap().into_usize(), targets.len());
+
+    for node in 0..graph.directed_node_count() {
+        let expected = graph
+            .iter_outgoing_edges(node.into())
+            .map(|edge| (edge.index().into_usize(), edge.to()))
+            .collect::<Vec<_>>();
+        let actual = (offsets[node].into_usize()..offsets[node + 1].into_usize())
+            .map(|edge| (edge, targets[edge]))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    let adjacency = graph.iter_adjacency().collect::<Vec<_>>();
+    assert_eq!(adjacency.len(), graph.directed_node_count());
+    for (index, (node, node_targets)) in adjacency.into_iter().enumerate() {
+        assert_eq!(node.into_usize(), index);
+        assert_eq!(
+            node_targets,
+            graph
+                .iter_outgoing_edges(node)
+                .map(|edge| edge.to())
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_iter_adjacent_nodes() {
+    let nodes = vec![(), (), (), ()];
+    let mut edges = Vec::new();
+    for (from, from_forward, to, to_forward) in [
+        (0, true, 1, true),
+        (1, false, 0, true),
+        (2, true, 0, false),
+        (0, false, 0, false),
+        (3, true, 3, false),
+    ] {
+        edges.push(BidirectedEdge {
+            from: from.into(),
+            from_forward,
+            to: to.into(),
+            to_forward,
+            data: (),
+        });
+    }
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(nodes.into(), edges.into());
+
+    let adjacent_nodes = |node: usize| {
+        graph
+            .iter_adjacent_nodes(node.into())
+            .map(|node| node.into_usize())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(adjacent_nodes(0), vec![0, 1, 2]);
+    assert_eq!(adjacent_nodes(1), vec![0]);
+    assert_eq!(adjacent_nodes(2), vec![0]);
+    assert_eq!(adjacent_nodes(3), vec![3]);
+}
+
+#[test]
+fn test_iter_where() {
+    let nodes = vec![3, 8, 5];
+    let edges = vec![
+        BidirectedEdge {
+            from: 0.into(),
+            from_forward: true,
+            to: 1.into(),
+            to_forward: true,
+            data: 0,
+        },
+        BidirectedEdge {
+            from: 1.into(),
+            from_forward: false,
+            to: 2.into(),
+            to_forward: true,
+            data: 10,
+        },
+        BidirectedEdge {
+            from: 2.into(),
+            from_forward: true,
+            to: 2.into(),
+            to_forward: true,
+            data: 20,
+        },
+    ];
+    let graph = BidirectedAdjacencyArray::<u8, i32, i32>::new(nodes.into(), edges.into());
+
+    assert_eq!(
+        graph.iter_nodes_where(|&data| data > 4).collect::<Vec<_>>(),
+        vec![1.into(), 2.into()]
+    );
+    assert_eq!(
+        graph.iter_edges_where(|&data| data > 0).collect::<Vec<_>>(),
+        vec![1.into(), 2.into()]
+    );
+    assert_eq!(graph.iter_edges_where(|_| false).count(), 0);
+}
+
+#[test]
+fn test_structural_eq() {
+    let edge = BidirectedEdge {
+        from: NodeIndex::<u8>::from(0),
+        from_forward: true,
+        to: 1.into(),
+        to_forward: false,
+        data: 1,
+    };
+    let same_endpoints = BidirectedEdge { data: 2, ..edge };
+    let reverse_complement = BidirectedEdge {
+        from: 1.into(),
+        from_forward: true,
+        to: 0.into(),
+        to_forward: false,
+        data: 1,
+    };
+
+    assert_ne!(edge, same_endpoints);
+    assert!(edge.structural_eq(&same_endpoints));
+    assert!(!edge.structural_eq(&reverse_complement));
+
+    let structural_hash = |edge: &BidirectedEdge<u8, i32>| {
+        let mut hasher = DefaultHasher::new();
+        edge.structural_hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(structural_hash(&edge), structural_hash(&same_endpoints));
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn test_generate_random_graph_without_parallel_edges() {
+    use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+    let mut rng = SmallRng::seed_from_u64(0);
+    // With 2 nodes there are only 16 combinations of endpoints, so random data would allow parallel edges.
+    let graph =
+        BidirectedAdjacencyArray::<u8, (), u32>::generate_random_graph_without_parallel_edges(
+            2,
+            10,
+            |_, _| (),
+            |rng| rng.random(),
+            &mut rng,
+        )
+        .unwrap();
+    assert_eq!(graph.edge_count(), 10);
+
+    let edges = graph
+        .iter_edges()
+        .map(|edge| graph.bidirected_edge(edge))
+        .collect::<Vec<_>>();
+    for (index, edge) in edges.iter().enumerate() {
+        assert!(
+            edges[index + 1..]
+                .iter()
+                .all(|other| !edge.structural_eq(other))
+        );
+    }
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn test_generate_random_graph_is_deterministic() {
+    use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+    let generate = || {
+        let mut rng = SmallRng::seed_from_u64(0);
+        BidirectedAdjacencyArray::<u8, u32, u32>::generate_random_graph(
+            10,
+            50,
+            |_, rng| rng.random(),
+            |rng| rng.random_range(0..3),
+            &mut rng,
+        )
+        .unwrap()
+    };
+    let graph = generate();
+    let other_graph = generate();
+
+    assert!(graph.compare(&other_graph).is_ok());
+    assert!(
+        graph
+            .iter_edges()
+            .all(|edge| graph.bidirected_edge(edge) == other_graph.bidirected_edge(edge))
+    );
+}
+
+#[test]
+fn test_canonical_edge() {
+    let edge = |from: usize, from_forward, to: usize, to_forward| BidirectedEdge {
+        from: NodeIndex::<u8>::from(from),
+        from_forward,
+        to: to.into(),
+        to_forward,
+        data: (),
+    };
+
+    // (1+, 0-) is the reverse complement of (0+, 1-).
+    assert_eq!(edge(1, true, 0, false).canonical(), edge(0, true, 1, false));
+    assert_eq!(edge(0, true, 1, false).canonical(), edge(0, true, 1, false));
+    // (1-, 0+) is the reverse complement of (0-, 1+).
+    assert_eq!(edge(1, false, 0, true).canonical(), edge(0, false, 1, true));
+    // Forward sides are ordered before reverse sides.
+    assert_eq!(edge(0, false, 0, false).canonical(), edge(0, true, 0, true));
+    // Edges that are their own reverse complement are unchanged.
+    assert_eq!(edge(2, true, 2, false).canonical(), edge(2, true, 2, false));
+    assert_eq!(edge(2, false, 2, true).canonical(), edge(2, false, 2, true));
+
+    for (from, from_forward, to, to_forward) in [(3, false, 1, false), (1, true, 3, true)] {
+        let edge = edge(from, from_forward, to, to_forward);
+        let reverse_complement = BidirectedEdge {
+            from: edge.to,
+            from_forward: !edge.to_forward,
+            to: edge.from,
+            to_forward: !edge.from_forward,
+            data: (),
+        };
+        assert_eq!(edge.clone().canonical(), reverse_complement.canonical());
+    }
+}
+
+#[test]
+fn test_iter_outgoing_edges_with_data() {
+    let nodes = vec![(), ()];
+    let mut edges = Vec::new();
+    for (index, (from, from_forward, to, to_forward)) in [
+        (0, true, 1, true),
+        (1, false, 0, false),
+        (0, true, 0, false),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        edges.push(BidirectedEdge {
+            from: from.into(),
+            from_forward,
+            to: to.into(),
+            to_forward,
+            data: index,
+        });
+    }
+    let graph = BidirectedAdjacencyArray::<u8, (), usize>::new(nodes.into(), edges.into());
+
+    for node in 0..graph.directed_node_count() {
+        let node = node.into();
+        let expected = graph
+            .iter_outgoing_edges(node)
+            .map(|edge| (edge.index(), graph.directed_edge_data(edge.index()).data()))
+            .collect::<Vec<_>>();
+        let actual = graph
+            .iter_outgoing_edges_with_data(node)
+            .map(|(edge, data)| (edge.index(), data))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    let mut data = graph
+        .iter_outgoing_edges_with_data(0.into())
+        .map(|(_, &data)| data)
+        .collect::<Vec<_>>();
+    data.sort();
+    // Both directed edges of the self loop 0+ -> 0- leave 0+.
+    assert_eq!(data, vec![0, 1, 2, 2]);
+}
+
+#[test]
+fn test_self_loop_kind() {
+    let nodes = vec![(), ()];
+    let mut edges = Vec::new();
+    for (from, from_forward, to, to_forward) in [
+        (0, true, 1, true),
+        (0, true, 0, true),
+        (0, false, 0, false),
+        (1, true, 1, false),
+        (1, false, 1, true),
+    ] {
+        edges.push(BidirectedEdge {
+            from: from.into(),
+            from_forward,
+            to: to.into(),
+            to_forward,
+            data: (),
+        });
+    }
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(nodes.into(), edges.into());
+
+    assert_eq!(
+        graph
+            .iter_edges()
+            .map(|edge| graph.self_loop_kind(edge))
+            .collect::<Vec<_>>(),
+        vec![
+            None,
+            Some(SelfLoopKind::PlusPlus),
+            Some(SelfLoopKind::MinusMinus),
+            Some(SelfLoopKind::PlusMinus),
+            Some(SelfLoopKind::MinusPlus),
+        ]
+    );
+    assert_eq!(
+        graph.iter_hairpin_edges().collect::<Vec<_>>(),
+        vec![1.into(), 2.into()]
+    );
+    assert_eq!(
+        graph.iter_palindromic_edges().collect::<Vec<_>>(),
+        vec![3.into(), 4.into()]
+    );
+}
+
+#[test]
+fn test_has_index() {
+    let nodes = vec![(), ()];
+    let edges = vec![BidirectedEdge {
+        from: 0.into(),
+        from_forward: true,
+        to: 1.into(),
+        to_forward: false,
+        data: (),
+    }];
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(nodes.into(), edges.into());
+
+    assert!(graph.has_node(1.into()));
+    assert!(!graph.has_node(2.into()));
+    assert!(graph.has_edge(0.into()));
+    assert!(!graph.has_edge(1.into()));
+    assert!(graph.has_directed_node(3.into()));
+    assert!(!graph.has_directed_node(4.into()));
+    assert!(graph.has_directed_edge(1.into()));
+    assert!(!graph.has_directed_edge(2.into()));
+}
+
+#[test]
+fn test_iter_directed_nodes() {
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(vec![(), ()].into(), Vec::new().into());
+    assert_eq!(
+        graph
+            .iter_directed_nodes()
+            .map(|node| (node.into_bidirected(), node.is_forward()))
+            .collect::<Vec<_>>(),
+        vec![
+            (0.into(), true),
+            (0.into(), false),
+            (1.into(), true),
+            (1.into(), false),
+        ]
+    );
+    assert_eq!(
+        BidirectedAdjacencyArray::<u8, (), ()>::empty()
+            .iter_directed_nodes()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn test_parse_checked_index() {
+    let nodes = vec![(), ()];
+    let edges = vec![BidirectedEdge {
+        from: 0.into(),
+        from_forward: true,
+        to: 1.into(),
+        to_forward: false,
+        data: (),
+    }];
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::new(nodes.into(), edges.into());
+
+    assert_eq!(NodeIndex::parse_checked("1", &graph), Ok(1.into()));
+    assert_eq!(NodeIndex::parse_checked(" 0\n", &graph), Ok(0.into()));
+    assert_eq!(
+        NodeIndex::parse_checked("2", &graph),
+        Err(ParseIndexError::NodeOutOfRange(2, 2))
+    );
+    assert_eq!(
+        NodeIndex::parse_checked("1000", &graph),
+        Err(ParseIndexError::NodeOutOfRange(1000, 2))
+    );
+    assert!(matches!(
+        NodeIndex::parse_checked("-1", &graph),
+        Err(ParseIndexError::InvalidNumber(..))
+    ));
+
+    assert_eq!(EdgeIndex::parse_checked("0", &graph), Ok(0.into()));
+    assert_eq!(
+        EdgeIndex::parse_checked("1", &graph),
+        Err(ParseIndexError::EdgeOutOfRange(1, 1))
+    );
+    assert_eq!(
+        EdgeIndex::parse_checked("x", &graph)
+            .unwrap_err()
+            .to_string(),
+        "'x' is not a valid index: invalid digit found in string"
+    );
+}
@@ -0,0 +1,630 @@
+use alloc::{
+    borrow::Cow,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{cmp::Ordering, mem};
+
+use tagged_vec::TaggedVec;
+use thiserror::Error;
+
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge, BidirectedEdgeData, EdgeDataKey, NodeSet},
+    index::{
+        DirectedEdgeIndex, DirectedNodeIndex, EdgeIndex, GraphIndexInteger, NodeIndex,
+        OptionalEdgeIndex, OptionalNodeIndex,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MergeError {
+    #[error("both graphs contain a node named '{0}', but with different data")]
+    ConflictingNodeData(String),
+}
+
+impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
+    BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    /// Transforms the node data with `f`, keeping the topology and all indices.
+    pub fn map_node_data<NewNodeData>(
+        self,
+        f: impl FnMut(NodeData) -> NewNodeData,
+    ) -> BidirectedAdjacencyArray<IndexType, NewNodeData, EdgeData> {
+        BidirectedAdjacencyArray {
+            node_array: self.node_array,
+            edge_array: self.edge_array,
+            node_data: self.node_data.into_values_iter().map(f).collect(),
+            edge_data_keys: self.edge_data_keys,
+            edge_data: self.edge_data,
+        }
+    }
+
+    /// Transforms the edge data with `f`, keeping the topology and all indices.
+    pub fn map_edge_data<NewEdgeData>(
+        self,
+        mut f: impl FnMut(EdgeData) -> NewEdgeData,
+    ) -> BidirectedAdjacencyArray<IndexType, NodeData, NewEdgeData> {
+        BidirectedAdjacencyArray {
+            node_array: self.node_array,
+            edge_array: self.edge_array,
+            node_data: self.node_data,
+            edge_data_keys: self.edge_data_keys,
+            edge_data: self
+                .edge_data
+                .into_values_iter()
+                .map(|edge_data| BidirectedEdgeData {
+                    forward: edge_data.forward,
+                    reverse: edge_data.reverse,
+                    data: f(edge_data.data),
+                })
+                .collect(),
+        }
+    }
+
+    /// Converts all stored indices into a wider integer type, keeping the topology and all indices.
+    pub fn widen<WideIndexType: GraphIndexInteger + From<IndexType>>(
+        self,
+    ) -> BidirectedAdjacencyArray<WideIndexType, NodeData, EdgeData> {
+        BidirectedAdjacencyArray {
+            node_array: self
+                .node_array
+                .into_values_iter()
+                .map(|edge| DirectedEdgeIndex::from_raw(edge.into_raw().into()))
+                .collect(),
+            edge_array: self
+                .edge_array
+                .into_values_iter()
+                .map(|node| DirectedNodeIndex::from_raw(node.into_raw().into()))
+                .collect(),
+            node_data: self.node_data.into_values_iter().collect(),
+            edge_data_keys: self
+                .edge_data_keys
+                .into_values_iter()
+                .map(|key| EdgeDataKey {
+                    inverse: DirectedEdgeIndex::from_raw(key.inverse.into_raw().into()),
+                    data_index: OptionalEdgeIndex::from_option(
+                        key.data_index
+                            .into_option()
+                            .map(|edge| EdgeIndex::from_raw(edge.into_raw().into())),
+                    ),
+                })
+                .collect(),
+            edge_data: self
+                .edge_data
+                .into_values_iter()
+                .map(|edge_data| BidirectedEdgeData {
+                    forward: DirectedEdgeIndex::from_raw(edge_data.forward.into_raw().into()),
+                    reverse: DirectedEdgeIndex::from_raw(edge_data.reverse.into_raw().into()),
+                    data: edge_data.data,
+                })
+                .collect(),
+        }
+    }
+
+    /// Adds the given edges to the graph, rebuilding the adjacency array once.
+    ///
+    /// The existing edges keep their indices, and the new edges are appended in the given order.
+    /// The result is equal to constructing the graph with [`Self::new`] from the combined edge list.
+    pub fn extend_edges(
+        &mut self,
+        edges: impl IntoIterator<Item = BidirectedEdge<IndexType, EdgeData>>,
+    ) {
+        let graph = mem::take(self);
+        let (nodes, mut existing_edges) = graph.into_edges();
+        for edge in edges {
+            existing_edges.push(edge);
+        }
+
+        *self = Self::new(nodes, existing_edges);
+    }
+
+    /// Merges `other` into this graph, identifying nodes with the same name.
+    ///
+    /// The nodes and edges of this graph keep their indices.
+    /// Each node of `other` is merged into the first node of this graph with the same name,
+    /// and all other nodes of `other` are appended in order.
+    /// The edges of `other` are appended after the edges of this graph, with their endpoints remapped to the merged nodes.
+    ///
+    /// If a node of `other` has the same name as a node of this graph but different data, then an error is returned.
+    ///
+    /// The names are returned as [`Cow`] such that [`GfaNodeData::name`](crate::data::GfaNodeData::name) can be passed directly.
+    pub fn merge_by_name(
+        self,
+        other: Self,
+        name_of: impl Fn(&NodeData) -> Cow<'_, str>,
+    ) -> Result<Self, MergeError>
+    where
+        NodeData: Eq,
+    {
+        let (mut nodes, mut edges) = self.into_edges();
+        let (other_nodes, other_edges) = other.into_edges();
+
+        let mut name_to_node = BTreeMap::new();
+        for (node, node_data) in nodes.iter() {
+            name_to_node
+                .entry(name_of(node_data).into_owned())
+                .or_insert(node);
+        }
+
+        let mut node_map = TaggedVec::<NodeIndex<IndexType>, NodeIndex<IndexType>>::new();
+        for node_data in other_nodes.into_values_iter() {
+            let name = name_of(&node_data);
+            if let Some(&node) = name_to_node.get(name.as_ref()) {
+                if nodes[node] != node_data {
+                    return Err(MergeError::ConflictingNodeData(name.to_string()));
+                }
+                node_map.push(node);
+            } else {
+                let name = name.into_owned();
+                let node = nodes.push(node_data);
+                name_to_node.insert(name, node);
+                node_map.push(node);
+            }
+        }
+
+        for edge in other_edges.into_values_iter() {
+            edges.push(BidirectedEdge {
+                from: node_map[edge.from],
+                to: node_map[edge.to],
+                ..edge
+            });
+        }
+
+        Ok(Self::new(nodes, edges))
+    }
+
+    /// Collapses parallel edges into a single edge.
+    ///
+    /// Edges are parallel if they have the same endpoints with the same orientations,
+    /// where an edge is also parallel to an edge in its reverse-complemental orientation.
+    /// The first edge of each group of parallel edges is kept at its relative position,
+    /// and the data of each further edge is combined into it with `merge(survivor, removed)`.
+    pub fn dedup_edges(&mut self, merge: impl Fn(&mut EdgeData, &EdgeData)) {
+        let graph = mem::take(self);
+        let (nodes, edges) = graph.into_edges();
+
+        let mut deduplicated_edges = TaggedVec::<EdgeIndex<IndexType>, BidirectedEdge<_, _>>::new();
+        let mut endpoints_to_edge = BTreeMap::new();
+        for edge in edges.into_values_iter() {
+            let from = DirectedNodeIndex::from_bidirected(edge.from, edge.from_forward);
+            let to = DirectedNodeIndex::from_bidirected(edge.to, edge.to_forward);
+            let endpoints = (from, to).min((to.invert(), from.invert()));
+
+            if let Some(&survivor) = endpoints_to_edge.get(&endpoints) {
+                merge(&mut deduplicated_edges[survivor].data, &edge.data);
+            } else {
+                endpoints_to_edge.insert(endpoints, deduplicated_edges.push(edge));
+            }
+        }
+
+        *self = Self::new(nodes, deduplicated_edges);
+    }
+
+    /// Stably sorts the nodes by the given key, remapping all edges to the new node indices.
+    ///
+    /// After sorting, [`Self::iter_nodes`] yields the nodes in ascending order of their key,
+    /// with nodes of equal keys keeping their relative order.
+    /// The edges keep their indices and connect the same node data as before.
+    pub fn sort_nodes_by_key<Key: Ord>(&mut self, key: impl Fn(&NodeData) -> Key) {
+        let graph = mem::take(self);
+        let (nodes, edges) = graph.into_edges();
+
+        let mut nodes = nodes.into_iter().collect::<Vec<_>>();
+        nodes.sort_by_key(|(_, node_data)| key(node_data));
+
+        let mut node_map = vec![NodeIndex::from_usize(0); nodes.len()];
+        for (new_node, (old_node, _)) in nodes.iter().enumerate() {
+            node_map[old_node.into_usize()] = NodeIndex::from_usize(new_node);
+        }
+
+        *self = Self::new(
+            nodes.into_iter().map(|(_, node_data)| node_data).collect(),
+            edges
+                .into_values_iter()
+                .map(|edge| BidirectedEdge {
+                    from: node_map[edge.from.into_usize()],
+                    to: node_map[edge.to.into_usize()],
+                    ..edge
+                })
+                .collect(),
+        );
+    }
+
+    /// Contracts the given edge, merging its two endpoints into a single node.
+    ///
+    /// Let the edge go from directed node `(a, a_forward)` to directed node `(b, b_forward)`.
+    /// Then the merged node in forward orientation corresponds to walking `a` in orientation `a_forward`
+    /// and then `b` in orientation `b_forward`.
+    /// Its data is `merge(a_data, a_forward, b_data, b_forward, edge_data)`.
+    ///
+    /// All other edges incident to `a` or `b` are redirected to the same side of the merged node,
+    /// where both `(a, a_forward)` and `(b, b_forward)` become the forward side, and their inverses the reverse side.
+    /// Further edges between `a` and `b` become self loops.
+    ///
+    /// The merged node takes the index of `a`, and the nodes after `b` as well as the edges after the contracted edge move down by one index.
+    /// The relative order of all other nodes and edges is kept.
+    ///
+    /// Panics if the edge is a self loop.
+    pub fn contract_edge(
+        &mut self,
+        edge: EdgeIndex<IndexType>,
+        merge: impl FnOnce(NodeData, bool, NodeData, bool, EdgeData) -> NodeData,
+    ) {
+        let (a, a_forward) = self.edge(edge).from_bidirected();
+        let (b, b_forward) = self.edge(edge).to_bidirected();
+        assert_ne!(a, b, "cannot contract self loop {edge}");
+
+        let graph = mem::take(self);
+        let (nodes, edges) = graph.into_edges();
+
+        let mut edges = edges.into_values_iter().collect::<Vec<_>>();
+        let contracted_edge = edges.remove(edge.into_usize());
+        let mut nodes = nodes.into_values_iter().map(Some).collect::<Vec<_>>();
+        let a_data = nodes[a.into_usize()].take().unwrap();
+        let b_data = nodes[b.into_usize()].take().unwrap();
+        nodes[a.into_usize()] = Some(merge(
+            a_data,
+            a_forward,
+            b_data,
+            b_forward,
+            contracted_edge.data,
+        ));
+        nodes.remove(b.into_usize());
+
+        let merged_node = if a < b {
+            a
+        } else {
+            NodeIndex::from_usize(a.into_usize() - 1)
+        };
+        let map_node = |node: NodeIndex<IndexType>, forward: bool| {
+            if node == a {
+                (merged_node, forward == a_forward)
+            } else if node == b {
+                (merged_node, forward == b_forward)
+            } else if node > b {
+                (NodeIndex::from_usize(node.into_usize() - 1), forward)
+            } else {
+                (node, forward)
+            }
+        };
+
+        *self = Self::new(
+            nodes.into_iter().map(Option::unwrap).collect(),
+            edges
+                .into_iter()
+                .map(|edge| {
+                    let (from, from_forward) = map_node(edge.from, edge.from_forward);
+                    let (to, to_forward) = map_node(edge.to, edge.to_forward);
+                    BidirectedEdge {
+                        from,
+                        from_forward,
+                        to,
+                        to_forward,
+                        data: edge.data,
+                    }
+                })
+                .collect(),
+        );
+    }
+
+    /// Removes the given edge and returns its data.
+    ///
+    /// The adjacency array is rebuilt, and the edges after the removed edge move down by one index.
+    /// The relative order of all other edges is kept, and the nodes keep their indices.
+    pub fn remove_edge(&mut self, edge: EdgeIndex<IndexType>) -> EdgeData {
+        let graph = mem::take(self);
+        let (nodes, edges) = graph.into_edges();
+
+        let mut edges = edges.into_values_iter().collect::<Vec<_>>();
+        let removed_edge = edges.remove(edge.into_usize());
+
+        *self = Self::new(nodes, edges.into());
+        removed_edge.data
+    }
+
+    /// Removes all edges for which `predicate` returns false.
+    ///
+    /// The adjacency array is rebuilt once, and the retained edges keep their relative order with consecutive indices.
+    /// The nodes keep their indices.
+    pub fn retain_edges(&mut self, predicate: impl Fn(EdgeIndex<IndexType>, &EdgeData) -> bool) {
+        let graph = mem::take(self);
+        let (nodes, edges) = graph.into_edges();
+
+        *self = Self::new(
+            nodes,
+            edges
+                .into_iter()
+                .filter(|(edge, bidirected_edge)| predicate(*edge, &bidirected_edge.data))
+                .map(|(_, bidirected_edge)| bidirected_edge)
+                .collect(),
+        );
+    }
+
+    /// Removes the given node together with all its incident edges.
+    ///
+    /// The adjacency array is rebuilt, and the nodes after the removed node move down by one index.
+    /// The remaining edges keep their relative order, with their indices reduced by the number of removed edges before them.
+    /// Returns the data of the removed node, and a map from the old edge indices to the new edge indices, which is `None` for removed edges.
+    pub fn remove_node(
+        &mut self,
+        node: NodeIndex<IndexType>,
+    ) -> (
+        NodeData,
+        TaggedVec<EdgeIndex<IndexType>, OptionalEdgeIndex<IndexType>>,
+    ) {
+        let graph = mem::take(self);
+        let (nodes, edges) = graph.into_edges();
+
+        let mut nodes = nodes.into_values_iter().collect::<Vec<_>>();
+        let removed_node = nodes.remove(node.into_usize());
+        let map_node = |other: NodeIndex<IndexType>| {
+            if other > node {
+                NodeIndex::from_usize(other.into_usize() - 1)
+            } else {
+                other
+            }
+        };
+
+        let mut edge_map = TaggedVec::<EdgeIndex<IndexType>, OptionalEdgeIndex<IndexType>>::new();
+        let mut retained_edges = TaggedVec::<EdgeIndex<IndexType>, _>::new();
+        for edge in edges.into_values_iter() {
+            if edge.from != node && edge.to != node {
+                edge_map.push(OptionalEdgeIndex::from_option(Some(retained_edges.push(
+                    BidirectedEdge {
+                        from: map_node(edge.from),
+                        to: map_node(edge.to),
+                        ..edge
+                    },
+                ))));
+            } else {
+                edge_map.push(OptionalEdgeIndex::new_none());
+            }
+        }
+
+        *self = Self::new(nodes.into(), retained_edges);
+        (removed_node, edge_map)
+    }
+
+    /// Removes all nodes for which `predicate` returns false, together with all their incident edges.
+    ///
+    /// The adjacency array is rebuilt once.
+    /// The retained nodes and edges keep their relative order with consecutive indices.
+    /// Returns a map from the old node indices to the new node indices, which is `None` for removed nodes.
+    pub fn retain_nodes(
+        &mut self,
+        predicate: impl Fn(NodeIndex<IndexType>, &NodeData) -> bool,
+    ) -> TaggedVec<NodeIndex<IndexType>, OptionalNodeIndex<IndexType>> {
+        let graph = mem::take(self);
+        let (nodes, edges) = graph.into_edges();
+
+        let mut node_map = TaggedVec::<NodeIndex<IndexType>, OptionalNodeIndex<IndexType>>::new();
+        let mut retained_nodes = TaggedVec::<NodeIndex<IndexType>, _>::new();
+        for (node, node_data) in nodes.into_iter() {
+            if predicate(node, &node_data) {
+                node_map.push(OptionalNodeIndex::from_option(Some(
+                    retained_nodes.push(node_data),
+                )));
+            } else {
+                node_map.push(OptionalNodeIndex::new_none());
+            }
+        }
+
+        *self = Self::new(
+            retained_nodes,
+            edges
+                .into_values_iter()
+                .filter_map(|edge| {
+                    Some(BidirectedEdge {
+                        from: node_map[edge.from].into_option()?,
+                        to: node_map[edge.to].into_option()?,
+                        ..edge
+                    })
+                })
+                .collect(),
+        );
+        node_map
+    }
+
+    /// Collapses each maximal non-branching path into a single node.
+    ///
+    /// An edge from directed node `u` to directed node `v` of a different bidirected node is compacted
+    /// if it is the only outgoing edge of `u` and the only incoming edge of `v`.
+    /// The maximal walks over compacted edges are the unitigs, where each bidirected node belongs to exactly one unitig.
+    /// A unitig that forms a cycle is cut open before its smallest node, and its closing edge becomes a self loop.
+    ///
+    /// Each unitig becomes one node, whose data is `concat` of the data of its nodes in walk order,
+    /// each paired with true if it is walked in forward orientation.
+    /// The forward orientation of the new node corresponds to the walk.
+    /// The new nodes are ordered by the smallest node they contain.
+    ///
+    /// All edges that are not compacted are kept in their relative order,
+    /// with their endpoints redirected to the corresponding sides of the new nodes.
+    pub fn compact_unitigs(&self, concat: impl Fn(&[(&NodeData, bool)]) -> NodeData) -> Self
+    where
+        EdgeData: Clone,
+    {
+        let out_degree =
+            |node: DirectedNodeIndex<IndexType>| self.iter_outgoing_edges(node).count();
+        // Returns the successor of `node` if the edge to it is compacted.
+        let compacted_successor = |node: DirectedNodeIndex<IndexType>| {
+            let mut edges = self.iter_outgoing_edges(node);
+            let edge = edges.next()?;
+            (edges.next().is_none()
+                && edge.to().into_bidirected() != node.into_bidirected()
+                && out_degree(edge.to().invert()) == 1)
+                .then_some(edge)
+        };
+
+        let mut visited = NodeSet::new(self.node_count());
+        let mut is_compacted = vec![false; self.edge_count()];
+        let mut node_map = vec![None; self.directed_node_count()];
+        let mut nodes = TaggedVec::<NodeIndex<IndexType>, _>::new();
+        for node in self.iter_nodes() {
+            if visited.contains(node) {
+                continue;
+            }
+
+            // Walk backwards to the start of the unitig.
+            let mut start = DirectedNodeIndex::from_bidirected(node, true);
+            while let Some(edge) = compacted_successor(start.invert()) {
+                let predecessor = edge.to().invert();
+                if predecessor.into_bidirected() == node {
+                    // The unitig is a cycle, so start it at the current node.
+                    start = DirectedNodeIndex::from_bidirected(node, true);
+                    break;
+                }
+                start = predecessor;
+            }
+
+            // Walk forwards to the end of the unitig.
+            let mut unitig = vec![start];
+            visited.insert(start.into_bidirected());
+            let mut current = start;
+            while let Some(edge) = compacted_successor(current) {
+                if visited.contains(edge.to().into_bidirected()) {
+                    break;
+                }
+                is_compacted[self
+                    .directed_edge_into_bidirected(edge.index())
+                    .into_usize()] = true;
+                current = edge.to();
+                unitig.push(current);
+                visited.insert(current.into_bidirected());
+            }
+
+            let new_node = NodeIndex::from_usize(nodes.len());
+            for &directed_node in &unitig {
+                node_map[directed_node.into_usize()] =
+                    Some(DirectedNodeIndex::from_bidirected(new_node, true));
+                node_map[directed_node.invert().into_usize()] =
+                    Some(DirectedNodeIndex::from_bidirected(new_node, false));
+            }
+            let unitig = unitig
+                .into_iter()
+                .map(|directed_node| {
+                    (
+                        self.node_data(directed_node.into_bidirected()),
+                        directed_node.is_forward(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            nodes.push(concat(&unitig));
+        }
+
+        let edges = self
+            .iter_edge_views()
+            .zip(is_compacted)
+            .filter(|(_, is_compacted)| !is_compacted)
+            .map(|(edge, _)| {
+                BidirectedEdge::new(
+                    node_map[edge.from().into_usize()].unwrap(),
+                    node_map[edge.to().into_usize()].unwrap(),
+                    edge.data().clone(),
+                )
+            })
+            .collect();
+
+        Self::new(nodes, edges)
+    }
+
+    /// Iterate over the edges in the order that [`Self::canonicalize`] would give them, without mutating the graph.
+    ///
+    /// This uses the same sort key as [`Self::canonicalize`]: the nodes are ranked by their data,
+    /// and the edges are sorted by the `(from, to)` tuple of their [canonical orientation](BidirectedEdge::canonical) under that ranking,
+    /// with parallel edges ordered by their data and then by their index.
+    /// The order is computed into a temporary vector when this is called, which takes `O(V log V + E log E)` time and `O(V + E)` memory.
+    pub fn iter_edges_canonical(&self) -> impl Iterator<Item = EdgeIndex<IndexType>>
+    where
+        NodeData: Ord,
+        EdgeData: Ord,
+    {
+        let node_map = self.canonical_node_map();
+        let mut edges = self
+            .iter_edges()
+            .map(|edge| (edge, canonical_edge(self.bidirected_edge(edge), &node_map)))
+            .collect::<Vec<_>>();
+        edges.sort_by(|(_, a), (_, b)| compare_canonical_edges(a, b));
+        edges.into_iter().map(|(edge, _)| edge)
+    }
+
+    /// Reorders the nodes and edges into a canonical order that does not depend on the order in which the graph was constructed.
+    ///
+    /// The nodes are stably sorted by their data, and the edges are remapped to the new node indices.
+    /// Then each edge is brought into its [canonical orientation](BidirectedEdge::canonical),
+    /// and the edges are sorted by their oriented `(from, to)` tuple, with parallel edges ordered by their data.
+    ///
+    /// Hence, isomorphic graphs whose nodes have pairwise distinct data are identical after canonicalization.
+    /// Nodes with equal data keep their relative order, so graphs with such nodes may still differ.
+    pub fn canonicalize(&mut self)
+    where
+        NodeData: Ord,
+        EdgeData: Ord,
+    {
+        let node_map = self.canonical_node_map();
+        let graph = mem::take(self);
+        let (nodes, edges) = graph.into_edges();
+
+        let mut nodes = nodes.into_iter().collect::<Vec<_>>();
+        nodes.sort_by_key(|(node, _)| node_map[node.into_usize()]);
+
+        let mut edges = edges
+            .into_values_iter()
+            .map(|edge| canonical_edge(edge, &node_map))
+            .collect::<Vec<_>>();
+        edges.sort_by(compare_canonical_edges);
+
+        *self = Self::new(
+            nodes.into_iter().map(|(_, node_data)| node_data).collect(),
+            edges.into_iter().collect(),
+        );
+    }
+
+    /// Maps each node to its index after [`Self::canonicalize`], i.e. its rank when the nodes are stably sorted by their data.
+    fn canonical_node_map(&self) -> Vec<NodeIndex<IndexType>>
+    where
+        NodeData: Ord,
+    {
+        let mut nodes = self.iter_nodes().collect::<Vec<_>>();
+        nodes.sort_by(|&a, &b| self.node_data(a).cmp(self.node_data(b)));
+
+        let mut node_map = vec![NodeIndex::from_usize(0); nodes.len()];
+        for (new_node, old_node) in nodes.into_iter().enumerate() {
+            node_map[old_node.into_usize()] = NodeIndex::from_usize(new_node);
+        }
+        node_map
+    }
+}
+
+/// Remaps the endpoints of the edge with the given node map and brings it into its canonical orientation.
+fn canonical_edge<IndexType: GraphIndexInteger, EdgeData>(
+    edge: BidirectedEdge<IndexType, EdgeData>,
+    node_map: &[NodeIndex<IndexType>],
+) -> BidirectedEdge<IndexType, EdgeData> {
+    BidirectedEdge {
+        from: node_map[edge.from.into_usize()],
+        to: node_map[edge.to.into_usize()],
+        ..edge
+    }
+    .canonical()
+}
+
+/// Orders canonical edges by their oriented `(from, to)` tuple, and parallel edges by their data.
+fn compare_canonical_edges<IndexType: GraphIndexInteger, EdgeData: Ord>(
+    a: &BidirectedEdge<IndexType, EdgeData>,
+    b: &BidirectedEdge<IndexType, EdgeData>,
+) -> Ordering {
+    let endpoints = |edge: &BidirectedEdge<IndexType, EdgeData>| {
+        (
+            DirectedNodeIndex::from_bidirected(edge.from, edge.from_forward),
+            DirectedNodeIndex::from_bidirected(edge.to, edge.to_forward),
+        )
+    };
+    endpoints(a)
+        .cmp(&endpoints(b))
+        .then_with(|| a.data.cmp(&b.data))
+}
@@ -0,0 +1,606 @@
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge, MergeError},
+    io::{
+        gfa1::{GfaEdgeData, GfaNodeData, PlainGfaEdgeData, PlainGfaNodeData, write_gfa1},
+        sequence::reverse_complement,
+    },
+    test_util::{directed, gfa_graph},
+};
+
+fn nodes() -> Vec<PlainGfaNodeData> {
+    vec![
+        PlainGfaNodeData::new("N0", "ACG"),
+        PlainGfaNodeData::new("N1", "CGT"),
+        PlainGfaNodeData::new("N2", "GTA"),
+    ]
+}
+
+fn gfa_string(graph: &BidirectedAdjacencyArray<u16, PlainGfaNodeData, PlainGfaEdgeData>) -> String {
+    let mut buffer = Vec::new();
+    write_gfa1(graph, &mut buffer).unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn test_canonicalize() {
+    let edges = vec![
+        BidirectedEdge::new_gfa(directed(1, true), directed(2, false), 1),
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, true), 2),
+        BidirectedEdge::new_gfa(directed(2, true), directed(2, false), 3),
+    ];
+    // The same edges in a different order, two of them in reverse-complemental orientation.
+    let permuted_edges = vec![
+        BidirectedEdge::new_gfa(directed(2, true), directed(2, false), 3),
+        BidirectedEdge::new_gfa(directed(1, false), directed(0, false), 2),
+        BidirectedEdge::new_gfa(directed(2, true), directed(1, false), 1),
+    ];
+
+    let mut graph = BidirectedAdjacencyArray::new(nodes().into(), edges.into());
+    let mut permuted_graph = BidirectedAdjacencyArray::new(nodes().into(), permuted_edges.into());
+    assert_ne!(gfa_string(&graph), gfa_string(&permuted_graph));
+
+    graph.canonicalize();
+    permuted_graph.canonicalize();
+    graph.expect_equal(&permuted_graph);
+    assert_eq!(gfa_string(&graph), gfa_string(&permuted_graph));
+
+    let first_edge = graph.edge(0.into());
+    assert_eq!(first_edge.from(), directed(0, true));
+    assert_eq!(first_edge.to(), directed(1, true));
+}
+
+#[test]
+fn test_canonicalize_permuted_nodes() {
+    let edges = vec![
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, true), 2),
+        BidirectedEdge::new_gfa(directed(1, true), directed(2, false), 1),
+        BidirectedEdge::new_gfa(directed(1, true), directed(2, false), 0),
+        BidirectedEdge::new_gfa(directed(2, true), directed(0, false), 3),
+    ];
+    // The same graph with the nodes in order N2, N0, N1, and the edges permuted and partially reversed.
+    let permuted_nodes = vec![
+        PlainGfaNodeData::new("N2", "GTA"),
+        PlainGfaNodeData::new("N0", "ACG"),
+        PlainGfaNodeData::new("N1", "CGT"),
+    ];
+    let permuted_edges = vec![
+        BidirectedEdge::new_gfa(directed(1, true), directed(0, false), 3),
+        BidirectedEdge::new_gfa(directed(0, true), directed(2, false), 0),
+        BidirectedEdge::new_gfa(directed(2, false), directed(1, false), 2),
+        BidirectedEdge::new_gfa(directed(2, true), directed(0, false), 1),
+    ];
+
+    let mut graph = BidirectedAdjacencyArray::new(nodes().into(), edges.into());
+    let mut permuted_graph =
+        BidirectedAdjacencyArray::new(permuted_nodes.into(), permuted_edges.into());
+    assert_ne!(gfa_string(&graph), gfa_string(&permuted_graph));
+
+    graph.canonicalize();
+    permuted_graph.canonicalize();
+    assert_eq!(gfa_string(&graph), gfa_string(&permuted_graph));
+    graph.expect_equal(&permuted_graph);
+
+    // Parallel edges are ordered by their data.
+    assert_eq!(graph.edge(2.into()).from(), directed(1, true));
+    assert_eq!(graph.edge(2.into()).data().overlap(), 0);
+    assert_eq!(graph.edge(3.into()).from(), directed(1, true));
+    assert_eq!(graph.edge(3.into()).data().overlap(), 1);
+}
+
+#[test]
+fn test_iter_edges_canonical() {
+    let edges = vec![
+        BidirectedEdge::new(directed(1, true), directed(2, false), 0),
+        BidirectedEdge::new(directed(1, false), directed(0, false), 1),
+        BidirectedEdge::new(directed(2, true), directed(2, false), 2),
+        BidirectedEdge::new(directed(0, true), directed(1, true), 3),
+    ];
+    let mut graph = BidirectedAdjacencyArray::<u16, _, u8>::new(nodes().into(), edges.into());
+
+    let canonical_order = graph.iter_edges_canonical().collect::<Vec<_>>();
+    assert_eq!(
+        canonical_order,
+        vec![1.into(), 3.into(), 0.into(), 2.into()]
+    );
+
+    graph.canonicalize();
+    assert_eq!(
+        graph
+            .iter_edges()
+            .map(|edge| *graph.edge(edge).data())
+            .collect::<Vec<_>>(),
+        vec![1, 3, 0, 2]
+    );
+    assert!(graph.iter_edges_canonical().eq(graph.iter_edges()));
+}
+
+#[test]
+fn test_iter_edges_canonical_matches_canonicalize() {
+    let edges = vec![
+        BidirectedEdge::new(directed(0, true), directed(1, false), 5),
+        BidirectedEdge::new(directed(2, false), directed(0, true), 4),
+        BidirectedEdge::new(directed(1, true), directed(2, true), 3),
+        BidirectedEdge::new(directed(0, true), directed(1, false), 2),
+        BidirectedEdge::new(directed(2, true), directed(2, false), 1),
+    ];
+    // The node data are the reverse of the node indices, so canonicalize reverses the nodes.
+    let mut graph =
+        BidirectedAdjacencyArray::<u16, u8, u8>::new(vec![2, 1, 0].into(), edges.into());
+
+    let expected = graph
+        .iter_edges_canonical()
+        .map(|edge| *graph.edge(edge).data())
+        .collect::<Vec<_>>();
+    assert_eq!(expected, vec![1, 3, 4, 2, 5]);
+
+    graph.canonicalize();
+    assert_eq!(graph.node_data(0.into()), &0);
+    assert_eq!(
+        graph
+            .iter_edges()
+            .map(|edge| *graph.edge(edge).data())
+            .collect::<Vec<_>>(),
+        expected
+    );
+    assert!(graph.iter_edges_canonical().eq(graph.iter_edges()));
+}
+
+#[test]
+fn test_map_data() {
+    let graph = gfa_graph(
+        nodes(),
+        &[
+            (directed(0, true), directed(1, false), 1),
+            (directed(2, false), directed(2, true), 2),
+        ],
+    );
+
+    let mapped_graph = graph
+        .map_node_data(|node_data| node_data.sequence_length().unwrap())
+        .map_edge_data(|edge_data| u32::from(edge_data.overlap()) * 10);
+
+    let expected_edges = vec![
+        BidirectedEdge::new(directed(0, true), directed(1, false), 10),
+        BidirectedEdge::new(directed(2, false), directed(2, true), 20),
+    ];
+    let expected_graph = BidirectedAdjacencyArray::new(vec![3, 3, 3].into(), expected_edges.into());
+    expected_graph.expect_equal(&mapped_graph);
+}
+
+#[test]
+fn test_extend_edges() {
+    let edges = vec![
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, true), 2),
+        BidirectedEdge::new_gfa(directed(1, true), directed(2, false), 1),
+    ];
+    let additional_edges = vec![
+        BidirectedEdge::new_gfa(directed(2, true), directed(2, false), 3),
+        BidirectedEdge::new_gfa(directed(0, false), directed(1, true), 4),
+    ];
+    let combined_edges = edges
+        .iter()
+        .chain(&additional_edges)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut graph = BidirectedAdjacencyArray::<u16, _, _>::new(nodes().into(), edges.into());
+    graph.extend_edges(additional_edges);
+    let expected_graph = BidirectedAdjacencyArray::new(nodes().into(), combined_edges.into());
+
+    expected_graph.expect_equal(&graph);
+    assert_eq!(graph.edge_count(), 4);
+}
+
+#[test]
+fn test_merge_by_name() {
+    let graph = gfa_graph(
+        nodes()[..2].to_vec(),
+        &[(directed(0, true), directed(1, true), 2)],
+    );
+    let other_nodes = vec![
+        PlainGfaNodeData::new("N3", "TTT"),
+        PlainGfaNodeData::new("N1", "CGT"),
+        PlainGfaNodeData::new("N2", "GTA"),
+    ];
+    let other = gfa_graph(
+        other_nodes,
+        &[
+            (directed(1, true), directed(2, false), 1),
+            (directed(0, false), directed(1, false), 4),
+        ],
+    );
+
+    let merged = graph.merge_by_name(other, GfaNodeData::name).unwrap();
+    let mut expected_nodes = nodes()[..2].to_vec();
+    expected_nodes.push(PlainGfaNodeData::new("N3", "TTT"));
+    expected_nodes.push(PlainGfaNodeData::new("N2", "GTA"));
+    let expected_graph = BidirectedAdjacencyArray::new(
+        expected_nodes.into(),
+        vec![
+            BidirectedEdge::new_gfa(directed(0, true), directed(1, true), 2),
+            BidirectedEdge::new_gfa(directed(1, true), directed(3, false), 1),
+            BidirectedEdge::new_gfa(directed(2, false), directed(1, false), 4),
+        ]
+        .into(),
+    );
+    expected_graph.expect_equal(&merged);
+
+    let graph = BidirectedAdjacencyArray::<u16, _, PlainGfaEdgeData>::new(
+        nodes().into(),
+        Vec::new().into(),
+    );
+    let other = BidirectedAdjacencyArray::new(
+        vec![PlainGfaNodeData::new("N1", "AAA")].into(),
+        Vec::new().into(),
+    );
+    assert_eq!(
+        graph.merge_by_name(other, GfaNodeData::name).unwrap_err(),
+        MergeError::ConflictingNodeData("N1".to_string())
+    );
+}
+
+#[test]
+fn test_dedup_edges() {
+    let edges = vec![
+        BidirectedEdge::new(directed(0, true), directed(1, true), 5),
+        BidirectedEdge::new(directed(1, true), directed(2, false), 1),
+        // Parallel to the first edge, in reverse-complemental orientation.
+        BidirectedEdge::new(directed(1, false), directed(0, false), 7),
+        // Same nodes, but different orientation.
+        BidirectedEdge::new(directed(0, true), directed(1, false), 2),
+        BidirectedEdge::new(directed(1, true), directed(2, false), 3),
+    ];
+    let mut graph = BidirectedAdjacencyArray::<u16, _, u32>::new(nodes().into(), edges.into());
+    graph.dedup_edges(|survivor, removed| *survivor += removed);
+
+    let expected_edges = vec![
+        BidirectedEdge::new(directed(0, true), directed(1, true), 12),
+        BidirectedEdge::new(directed(1, true), directed(2, false), 4),
+        BidirectedEdge::new(directed(0, true), directed(1, false), 2),
+    ];
+    let expected_graph = BidirectedAdjacencyArray::new(nodes().into(), expected_edges.into());
+    expected_graph.expect_equal(&graph);
+    assert_eq!(graph.edge_count(), 3);
+}
+
+#[test]
+fn test_sort_nodes_by_key() {
+    let mut graph = gfa_graph(
+        nodes(),
+        &[
+            (directed(0, true), directed(1, false), 1),
+            (directed(2, true), directed(0, true), 2),
+            (directed(2, false), directed(2, true), 3),
+        ],
+    );
+    let original_gfa = gfa_string(&graph);
+
+    graph.sort_nodes_by_key(|node_data| std::cmp::Reverse(node_data.name().into_owned()));
+
+    assert_eq!(
+        graph
+            .iter_node_data()
+            .map(|(_, node_data)| node_data.name().into_owned())
+            .collect::<Vec<_>>(),
+        vec!["N2", "N1", "N0"]
+    );
+    let expected_edges = vec![
+        BidirectedEdge::new_gfa(directed(2, true), directed(1, false), 1),
+        BidirectedEdge::new_gfa(directed(0, true), directed(2, true), 2),
+        BidirectedEdge::new_gfa(directed(0, false), directed(0, true), 3),
+    ];
+    let mut expected_nodes = nodes();
+    expected_nodes.reverse();
+    let expected_graph =
+        BidirectedAdjacencyArray::new(expected_nodes.into(), expected_edges.into());
+    expected_graph.expect_equal(&graph);
+
+    // The same segments and links, only the segments are listed in a different order.
+    let mut original_lines = original_gfa.lines().collect::<Vec<_>>();
+    let actual_gfa = gfa_string(&graph);
+    let mut actual_lines = actual_gfa.lines().collect::<Vec<_>>();
+    original_lines.sort();
+    actual_lines.sort();
+    assert_eq!(original_lines, actual_lines);
+}
+
+#[test]
+fn test_widen() {
+    let edges = vec![
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, false), 1),
+        BidirectedEdge::new_gfa(directed(2, true), directed(0, true), 2),
+        BidirectedEdge::new_gfa(directed(2, false), directed(2, true), 3),
+        BidirectedEdge::new_gfa(directed(1, true), directed(1, true), 4),
+    ];
+    let graph = BidirectedAdjacencyArray::new(nodes().into(), edges.clone().into());
+    let expected_gfa = gfa_string(&graph);
+
+    let wide_graph: BidirectedAdjacencyArray<u64, _, _> = graph.widen();
+    let expected_graph = BidirectedAdjacencyArray::<u64, _, _>::new(
+        nodes().into(),
+        edges
+            .into_iter()
+            .map(|edge| BidirectedEdge {
+                from: edge.from.into_usize().into(),
+                from_forward: edge.from_forward,
+                to: edge.to.into_usize().into(),
+                to_forward: edge.to_forward,
+                data: edge.data,
+            })
+            .collect(),
+    );
+    expected_graph.expect_equal(&wide_graph);
+
+    let mut buffer = Vec::new();
+    write_gfa1(&wide_graph, &mut buffer).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), expected_gfa);
+}
+
+#[test]
+fn test_contract_edge() {
+    // N0+ -> N1- is contracted into a node spelling ACG + rc(CGT)[1..] = ACG + CG.
+    let mut graph = gfa_graph(
+        nodes(),
+        &[
+            (directed(2, true), directed(0, true), 0),
+            (directed(0, true), directed(1, false), 1),
+            (directed(1, true), directed(2, false), 2),
+            (directed(1, false), directed(0, false), 3),
+        ],
+    );
+
+    graph.contract_edge(1.into(), |a, a_forward, b, b_forward, edge| {
+        assert!(a_forward);
+        assert!(!b_forward);
+        let b_sequence = reverse_complement(&b.sequence().unwrap());
+        PlainGfaNodeData::new(
+            format!("{}{}", a.name(), b.name()),
+            format!(
+                "{}{}",
+                a.sequence().unwrap(),
+                &b_sequence[usize::from(edge.overlap())..]
+            ),
+        )
+    });
+
+    let expected_nodes = vec![
+        PlainGfaNodeData::new("N0N1", "ACGCG"),
+        PlainGfaNodeData::new("N2", "GTA"),
+    ];
+    let expected_edges = vec![
+        // N2+ -> N0+ enters the merged node at its start.
+        BidirectedEdge::new_gfa(directed(1, true), directed(0, true), 0),
+        // N1+ is N1 in reverse to its orientation in the merged node, so its outgoing edges leave the reverse side.
+        BidirectedEdge::new_gfa(directed(0, false), directed(1, false), 2),
+        // N1- -> N0- connects two sides of the merged node, since N1- maps to its forward and N0- to its reverse orientation,
+        // so it becomes a self loop.
+        BidirectedEdge::new_gfa(directed(0, true), directed(0, false), 3),
+    ];
+    let expected_graph =
+        BidirectedAdjacencyArray::new(expected_nodes.into(), expected_edges.into());
+    expected_graph.expect_equal(&graph);
+}
+
+#[test]
+#[should_panic]
+fn test_contract_self_loop() {
+    let mut graph = gfa_graph(nodes(), &[(directed(0, true), directed(0, false), 0)]);
+    graph.contract_edge(0.into(), |a, _, _, _, _| a);
+}
+
+#[test]
+fn test_remove_edge_and_node() {
+    let mut graph = gfa_graph(
+        nodes(),
+        &[
+            (directed(2, true), directed(0, true), 0),
+            (directed(0, true), directed(1, false), 1),
+            (directed(1, true), directed(2, false), 2),
+            (directed(2, false), directed(2, true), 3),
+        ],
+    );
+
+    assert_eq!(graph.remove_edge(1.into()).overlap(), 1);
+    let expected_edges = vec![
+        BidirectedEdge::new_gfa(directed(2, true), directed(0, true), 0),
+        BidirectedEdge::new_gfa(directed(1, true), directed(2, false), 2),
+        BidirectedEdge::new_gfa(directed(2, false), directed(2, true), 3),
+    ];
+    let expected_graph = BidirectedAdjacencyArray::new(nodes().into(), expected_edges.into());
+    expected_graph.expect_equal(&graph);
+
+    let (node_data, edge_map) = graph.remove_node(0.into());
+    assert_eq!(node_data.name(), "N0");
+    assert_eq!(
+        edge_map
+            .into_values_iter()
+            .map(|edge| edge.into_option())
+            .collect::<Vec<_>>(),
+        vec![None, Some(0.into()), Some(1.into())]
+    );
+    let expected_nodes = nodes().into_iter().skip(1).collect::<Vec<_>>();
+    let expected_edges = vec![
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, false), 2),
+        BidirectedEdge::new_gfa(directed(1, false), directed(1, true), 3),
+    ];
+    let expected_graph =
+        BidirectedAdjacencyArray::new(expected_nodes.into(), expected_edges.into());
+    expected_graph.expect_equal(&graph);
+
+    // Removing a node with a self loop removes the self loop.
+    let (node_data, edge_map) = graph.remove_node(1.into());
+    assert_eq!(node_data.name(), "N2");
+    assert_eq!(
+        edge_map
+            .into_values_iter()
+            .map(|edge| edge.into_option())
+            .collect::<Vec<_>>(),
+        vec![None, None]
+    );
+    let expected_nodes = vec![PlainGfaNodeData::new("N1", "CGT")];
+    let expected_graph = BidirectedAdjacencyArray::new(expected_nodes.into(), Vec::new().into());
+    expected_graph.expect_equal(&graph);
+}
+
+#[test]
+fn test_retain_edges() {
+    let mut graph = gfa_graph(
+        nodes(),
+        &[
+            (directed(2, true), directed(0, true), 0),
+            (directed(0, true), directed(1, false), 3),
+            (directed(1, true), directed(2, false), 1),
+            (directed(2, false), directed(2, true), 2),
+        ],
+    );
+
+    graph.retain_edges(|_, edge_data| edge_data.overlap() >= 2);
+    let expected_edges = vec![
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, false), 3),
+        BidirectedEdge::new_gfa(directed(2, false), directed(2, true), 2),
+    ];
+    let expected_graph = BidirectedAdjacencyArray::new(nodes().into(), expected_edges.into());
+    expected_graph.expect_equal(&graph);
+
+    graph.retain_edges(|edge, _| edge != 0.into());
+    let expected_edges = vec![BidirectedEdge::new_gfa(
+        directed(2, false),
+        directed(2, true),
+        2,
+    )];
+    let expected_graph = BidirectedAdjacencyArray::new(nodes().into(), expected_edges.into());
+    expected_graph.expect_equal(&graph);
+
+    graph.retain_edges(|_, _| false);
+    let expected_graph = BidirectedAdjacencyArray::new(nodes().into(), Vec::new().into());
+    expected_graph.expect_equal(&graph);
+}
+
+#[test]
+fn test_retain_nodes() {
+    let mut graph = gfa_graph(
+        nodes(),
+        &[
+            (directed(2, true), directed(0, true), 0),
+            (directed(0, true), directed(1, false), 1),
+            (directed(1, true), directed(2, false), 2),
+            (directed(2, false), directed(2, true), 3),
+        ],
+    );
+
+    let node_map = graph.retain_nodes(|_, node_data| node_data.name() != "N1");
+    assert_eq!(
+        node_map
+            .iter_values()
+            .map(|node| node.into_option())
+            .collect::<Vec<_>>(),
+        vec![Some(0.into()), None, Some(1.into())]
+    );
+    let expected_nodes = vec![
+        PlainGfaNodeData::new("N0", "ACG"),
+        PlainGfaNodeData::new("N2", "GTA"),
+    ];
+    let expected_edges = vec![
+        BidirectedEdge::new_gfa(directed(1, true), directed(0, true), 0),
+        BidirectedEdge::new_gfa(directed(1, false), directed(1, true), 3),
+    ];
+    let expected_graph =
+        BidirectedAdjacencyArray::new(expected_nodes.into(), expected_edges.into());
+    expected_graph.expect_equal(&graph);
+
+    // Removing a node with a self loop removes the self loop.
+    let node_map = graph.retain_nodes(|node, _| node == 0.into());
+    assert_eq!(
+        node_map
+            .iter_values()
+            .map(|node| node.into_option())
+            .collect::<Vec<_>>(),
+        vec![Some(0.into()), None]
+    );
+    let expected_nodes = vec![PlainGfaNodeData::new("N0", "ACG")];
+    let expected_graph = BidirectedAdjacencyArray::new(expected_nodes.into(), Vec::new().into());
+    expected_graph.expect_equal(&graph);
+}
+
+fn concat_names(nodes: &[(&PlainGfaNodeData, bool)]) -> PlainGfaNodeData {
+    PlainGfaNodeData::new_without_sequence(
+        nodes
+            .iter()
+            .map(|(node, forward)| format!("{}{}", node.name(), if *forward { '+' } else { '-' }))
+            .collect::<String>(),
+    )
+}
+
+#[test]
+fn test_compact_unitigs() {
+    let nodes = (0..6)
+        .map(|node| PlainGfaNodeData::new_without_sequence(format!("N{node}")))
+        .collect::<Vec<_>>();
+    let graph = gfa_graph(
+        nodes,
+        &[
+            (directed(1, true), directed(2, false), 1),
+            (directed(0, false), directed(1, true), 0),
+            // The unitig branches at its end.
+            (directed(0, true), directed(3, true), 3),
+            (directed(0, true), directed(4, false), 4),
+            // Node 4 has two incoming edges, so this edge is not compacted.
+            (directed(5, true), directed(4, false), 5),
+        ],
+    );
+
+    let compacted_graph = graph.compact_unitigs(concat_names);
+
+    let expected_nodes = vec![
+        PlainGfaNodeData::new_without_sequence("N2+N1-N0+"),
+        PlainGfaNodeData::new_without_sequence("N3+"),
+        PlainGfaNodeData::new_without_sequence("N4+"),
+        PlainGfaNodeData::new_without_sequence("N5+"),
+    ];
+    let expected_edges = vec![
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, true), 3),
+        BidirectedEdge::new_gfa(directed(0, true), directed(2, false), 4),
+        BidirectedEdge::new_gfa(directed(3, true), directed(2, false), 5),
+    ];
+    let expected_graph =
+        BidirectedAdjacencyArray::new(expected_nodes.into(), expected_edges.into());
+    expected_graph.expect_equal(&compacted_graph);
+}
+
+#[test]
+fn test_compact_unitigs_cycle() {
+    let graph = gfa_graph(
+        nodes(),
+        &[
+            (directed(1, true), directed(2, false), 1),
+            (directed(2, false), directed(0, true), 2),
+            (directed(0, true), directed(1, true), 3),
+        ],
+    );
+
+    let compacted_graph = graph.compact_unitigs(concat_names);
+
+    let expected_nodes = vec![PlainGfaNodeData::new_without_sequence("N0+N1+N2-")];
+    let expected_edges = vec![BidirectedEdge::new_gfa(
+        directed(0, true),
+        directed(0, true),
+        2,
+    )];
+    let expected_graph =
+        BidirectedAdjacencyArray::new(expected_nodes.into(), expected_edges.into());
+    expected_graph.expect_equal(&compacted_graph);
+
+    // A graph without compactable edges is unchanged apart from the node data.
+    let graph = gfa_graph(
+        nodes(),
+        &[
+            (directed(0, true), directed(1, true), 1),
+            (directed(0, true), directed(2, true), 2),
+        ],
+    );
+    let compacted_graph = graph
+        .compact_unitigs(|nodes| nodes[0].0.clone())
+        .map_node_data(|node| node.name().into_owned());
+    let graph = graph.map_node_data(|node| node.name().into_owned());
+    graph.expect_equal(&compacted_graph);
+}
@@ -0,0 +1,255 @@
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    vec::Vec,
+};
+use core::{cmp::Reverse, iter};
+
+use num_traits::Zero;
+
+use tagged_vec::TaggedVec;
+
+use crate::{
+    data::{GfaEdgeData, GfaNodeData},
+    graph::{BidirectedAdjacencyArray, DirectedNodeSet},
+    index::{DirectedEdgeIndex, DirectedNodeIndex, GraphIndexInteger, NodeIndex},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// For each directed node, the directed node and edge via which it was first reached.
+type Predecessors<IndexType> = TaggedVec<
+    DirectedNodeIndex<IndexType>,
+    Option<(DirectedNodeIndex<IndexType>, DirectedEdgeIndex<IndexType>)>,
+>;
+
+impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
+    BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    /// Computes a shortest path from `from` to `to` by number of edges using a breadth-first search.
+    ///
+    /// The path is returned as the sequence of directed edges it traverses,
+    /// such that it is clear which of several parallel edges was used.
+    /// Returns `None` if `to` is not reachable from `from`, and an empty path if `from == to`.
+    pub fn shortest_path(
+        &self,
+        from: DirectedNodeIndex<IndexType>,
+        to: DirectedNodeIndex<IndexType>,
+    ) -> Option<Vec<DirectedEdgeIndex<IndexType>>> {
+        let mut predecessors: Predecessors<IndexType> =
+            TaggedVec::from_iter(iter::repeat_n(None, self.node_count() * 2));
+        let mut visited = DirectedNodeSet::new(self.node_count());
+        let mut queue = VecDeque::new();
+
+        visited.insert(from);
+        queue.push_back(from);
+
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                return Some(Self::reconstruct_path(&predecessors, from, to));
+            }
+
+            for edge in self.iter_outgoing_edges(node) {
+                if visited.insert(edge.to()) {
+                    predecessors[edge.to()] = Some((edge.from(), edge.index()));
+                    queue.push_back(edge.to());
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Computes a shortest path from `from` to `to` using Dijkstra's algorithm.
+    ///
+    /// The cost of traversing an edge is given by `weight` applied to its data.
+    /// Both directed edges of a bidirected edge have the same cost.
+    /// Negative weights are not supported and lead to wrong results.
+    ///
+    /// Returns the cost of the path and the sequence of directed edges it traverses,
+    /// or `None` if `to` is not reachable from `from`.
+    /// If `from == to`, then the path is empty and has zero cost.
+    pub fn shortest_path_weighted<Weight: Ord + Zero + Clone>(
+        &self,
+        from: DirectedNodeIndex<IndexType>,
+        to: DirectedNodeIndex<IndexType>,
+        weight: impl Fn(&EdgeData) -> Weight,
+    ) -> Option<(Weight, Vec<DirectedEdgeIndex<IndexType>>)> {
+        self.dijkstra(from, to, |_, edge_data| weight(edge_data))
+    }
+
+    /// Dijkstra's algorithm where the cost of an edge is given by `weight` applied to its target and its data.
+    fn dijkstra<Weight: Ord + Zero + Clone>(
+        &self,
+        from: DirectedNodeIndex<IndexType>,
+        to: DirectedNodeIndex<IndexType>,
+        weight: impl Fn(DirectedNodeIndex<IndexType>, &EdgeData) -> Weight,
+    ) -> Option<(Weight, Vec<DirectedEdgeIndex<IndexType>>)> {
+        let mut predecessors: Predecessors<IndexType> =
+            TaggedVec::from_iter(iter::repeat_n(None, self.node_count() * 2));
+        let mut distances = TaggedVec::<DirectedNodeIndex<IndexType>, Option<Weight>>::from_iter(
+            iter::repeat_n(None, self.node_count() * 2),
+        );
+        let mut finished = DirectedNodeSet::new(self.node_count());
+        let mut queue = BinaryHeap::new();
+
+        distances[from] = Some(Weight::zero());
+        queue.push(Reverse((Weight::zero(), from)));
+
+        while let Some(Reverse((distance, node))) = queue.pop() {
+            if !finished.insert(node) {
+                continue;
+            }
+
+            if node == to {
+                return Some((distance, Self::reconstruct_path(&predecessors, from, to)));
+            }
+
+            for edge in self.iter_outgoing_edges(node) {
+                if finished.contains(edge.to()) {
+                    continue;
+                }
+
+                let edge_weight = weight(edge.to(), self.directed_edge_data_ref(edge.index()));
+                let successor_distance = distance.clone() + edge_weight;
+                if distances[edge.to()]
+                    .as_ref()
+                    .is_none_or(|current| successor_distance < *current)
+                {
+                    distances[edge.to()] = Some(successor_distance.clone());
+                    predecessors[edge.to()] = Some((node, edge.index()));
+                    queue.push(Reverse((successor_distance, edge.to())));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Computes the set of directed nodes reachable from any of the `seeds` using a breadth-first search.
+    ///
+    /// The seeds themselves are always contained in the result.
+    /// If `max_depth` is given, then only nodes that are reachable via at most that many edges are visited.
+    pub fn reachable_from(
+        &self,
+        seeds: &[DirectedNodeIndex<IndexType>],
+        max_depth: Option<usize>,
+    ) -> DirectedNodeSet<IndexType> {
+        let mut visited = DirectedNodeSet::new(self.node_count());
+        let mut queue = VecDeque::new();
+
+        for &seed in seeds {
+            if visited.insert(seed) {
+                queue.push_back((seed, 0));
+            }
+        }
+
+        while let Some((node, depth)) = queue.pop_front() {
+            if max_depth.is_some_and(|max_depth| depth >= max_depth) {
+                continue;
+            }
+
+            for edge in self.iter_outgoing_edges(node) {
+                if visited.insert(edge.to()) {
+                    queue.push_back((edge.to(), depth + 1));
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// Returns true if the directed representation of the graph contains no directed cycle.
+    ///
+    /// Runs an iterative depth-first search that stops at the first back edge.
+    /// A self loop whose directed edges are loops on a single directed node, i.e. an edge from `n+` to `n+`
+    /// in the notation of [`BidirectedEdge::new`](crate::graph::BidirectedEdge::new), counts as a cycle.
+    /// A self loop connecting the two sides of a node, i.e. from `n+` to `n-`, is not a cycle by itself.
+    pub fn is_dag(&self) -> bool {
+        let mut discovered = DirectedNodeSet::new(self.node_count());
+        let mut finished = DirectedNodeSet::new(self.node_count());
+        let mut stack = Vec::new();
+
+        for root in self.iter_directed_nodes() {
+            if !discovered.insert(root) {
+                continue;
+            }
+            stack.push((root, self.iter_outgoing_edges(root)));
+
+            while let Some((node, outgoing_edges)) = stack.last_mut() {
+                if let Some(edge) = outgoing_edges.next() {
+                    let successor = edge.to();
+                    if discovered.insert(successor) {
+                        stack.push((successor, self.iter_outgoing_edges(successor)));
+                    } else if !finished.contains(successor) {
+                        // The successor is on the stack, so this is a back edge.
+                        return false;
+                    }
+                } else {
+                    finished.insert(*node);
+                    stack.pop();
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Follows the predecessor pointers backwards from `to` until `from` is reached.
+    fn reconstruct_path(
+        predecessors: &Predecessors<IndexType>,
+        from: DirectedNodeIndex<IndexType>,
+        to: DirectedNodeIndex<IndexType>,
+    ) -> Vec<DirectedEdgeIndex<IndexType>> {
+        let mut path = Vec::new();
+        let mut current = to;
+
+        while current != from {
+            let (predecessor, edge) =
+                predecessors[current].expect("node on path has no predecessor");
+            path.push(edge);
+            current = predecessor;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+impl<IndexType: GraphIndexInteger, NodeData: GfaNodeData, EdgeData>
+    BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    /// Returns the length of the sequence of the given node, or zero if it is unknown.
+    pub fn node_sequence_length(&self, node: NodeIndex<IndexType>) -> usize {
+        self.node_data(node).sequence_length().unwrap_or(0)
+    }
+
+    /// Computes a path from `from` to `to` whose spelled sequence is shortest, using Dijkstra's algorithm.
+    ///
+    /// Traversing an edge costs the sequence length of its target minus the overlap of the edge,
+    /// which is the number of characters the target adds to the spelled sequence, see [`Self::spell_path`].
+    /// If the overlap is longer than the target, then the edge costs zero.
+    /// The returned length is the length of the spelled sequence of the whole path,
+    /// i.e. it includes the full sequence of `from`, and the overlap into `to` is not counted twice.
+    /// Nodes with unknown sequence length count as length zero.
+    ///
+    /// Returns `None` if `to` is not reachable from `from`.
+    /// If `from == to`, then the path is empty and its length is the sequence length of `from`.
+    pub fn shortest_path_by_sequence_length(
+        &self,
+        from: DirectedNodeIndex<IndexType>,
+        to: DirectedNodeIndex<IndexType>,
+    ) -> Option<(usize, Vec<DirectedEdgeIndex<IndexType>>)>
+    where
+        EdgeData: GfaEdgeData,
+    {
+        let (length, path) = self.dijkstra(from, to, |target, edge_data| {
+            self.node_sequence_length(target.into_bidirected())
+                .saturating_sub(usize::from(edge_data.overlap()))
+        })?;
+        Some((
+            self.node_sequence_length(from.into_bidirected()) + length,
+            path,
+        ))
+    }
+}
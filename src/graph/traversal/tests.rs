@@ -0,0 +1,196 @@
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge},
+    io::gfa1::{PlainGfaEdgeData, PlainGfaNodeData},
+    test_util::{directed, graph},
+};
+
+/// A path 0 -> 1 -> 2 with a parallel edge 1 -> 2 and a shortcut 0 -> 2 that enters the reverse side of 2.
+fn example_graph() -> BidirectedAdjacencyArray<u8, (), u8> {
+    graph(
+        vec![(); 4],
+        vec![
+            (directed(0, true), directed(1, true), 0),
+            (directed(1, true), directed(2, true), 1),
+            (directed(1, true), directed(2, true), 2),
+            (directed(0, true), directed(2, false), 3),
+        ],
+    )
+}
+
+#[test]
+fn test_shortest_path() {
+    let graph = example_graph();
+
+    let path = graph
+        .shortest_path(directed(0, true), directed(2, true))
+        .unwrap();
+    assert_eq!(path.len(), 2);
+    assert_eq!(*graph.directed_edge_data(path[0]).data(), 0);
+    assert!([1, 2].contains(graph.directed_edge_data(path[1]).data()));
+
+    // The reverse-complement path enters node 0 from its reverse side.
+    let path = graph
+        .shortest_path(directed(2, false), directed(0, false))
+        .unwrap();
+    assert_eq!(path.len(), 2);
+
+    let path = graph
+        .shortest_path(directed(0, true), directed(2, false))
+        .unwrap();
+    assert_eq!(path.len(), 1);
+    assert_eq!(*graph.directed_edge_data(path[0]).data(), 3);
+}
+
+#[test]
+fn test_shortest_path_trivial_and_unreachable() {
+    let graph = example_graph();
+
+    assert_eq!(
+        graph.shortest_path(directed(1, false), directed(1, false)),
+        Some(Vec::new())
+    );
+    assert_eq!(
+        graph.shortest_path(directed(2, true), directed(0, true)),
+        None
+    );
+    assert_eq!(
+        graph.shortest_path(directed(0, true), directed(3, true)),
+        None
+    );
+}
+
+#[test]
+fn test_shortest_path_weighted() {
+    let graph = example_graph();
+
+    // The direct edge has weight 3, while the path over node 1 has weight 0 + 1.
+    let (cost, path) = graph
+        .shortest_path_weighted(directed(0, true), directed(2, true), |&data| {
+            u32::from(data)
+        })
+        .unwrap();
+    assert_eq!(cost, 1);
+    assert_eq!(
+        path.iter()
+            .map(|&edge| *graph.directed_edge_data(edge).data())
+            .collect::<Vec<_>>(),
+        vec![0, 1]
+    );
+
+    let (cost, path) = graph
+        .shortest_path_weighted(directed(0, true), directed(2, false), |&data| {
+            u32::from(data)
+        })
+        .unwrap();
+    assert_eq!(cost, 3);
+    assert_eq!(path.len(), 1);
+
+    assert_eq!(
+        graph.shortest_path_weighted(directed(3, true), directed(3, true), |_| 1u32),
+        Some((0, Vec::new()))
+    );
+    assert_eq!(
+        graph.shortest_path_weighted(directed(2, true), directed(0, true), |_| 1u32),
+        None
+    );
+}
+
+#[test]
+fn test_reachable_from() {
+    let graph = example_graph();
+
+    let reachable = graph.reachable_from(&[directed(0, true)], Some(1));
+    assert_eq!(
+        reachable.iter().collect::<Vec<_>>(),
+        vec![directed(0, true), directed(1, true), directed(2, false)],
+    );
+
+    let reachable = graph.reachable_from(&[directed(0, true)], None);
+    assert_eq!(reachable.len(), 6);
+    assert!(!reachable.contains(directed(3, true)));
+
+    let reachable = graph.reachable_from(&[directed(0, true), directed(3, false)], Some(0));
+    assert_eq!(
+        reachable.iter().collect::<Vec<_>>(),
+        vec![directed(0, true), directed(3, false)],
+    );
+
+    assert!(graph.reachable_from(&[], None).is_empty());
+}
+
+#[test]
+fn test_shortest_path_by_sequence_length() {
+    let nodes = vec![
+        PlainGfaNodeData::new_with_length("N0", 5),
+        PlainGfaNodeData::new_with_length("N1", 10),
+        PlainGfaNodeData::new_with_length("N2", 3),
+        PlainGfaNodeData::new_with_length("N3", 4),
+        PlainGfaNodeData::new_without_sequence("N4"),
+    ];
+    let edges = vec![
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, true), 2),
+        BidirectedEdge::new_gfa(directed(1, true), directed(3, true), 1),
+        BidirectedEdge::new_gfa(directed(0, true), directed(2, true), 0),
+        BidirectedEdge::new_gfa(directed(2, true), directed(4, true), 0),
+        BidirectedEdge::new_gfa(directed(4, true), directed(3, true), 0),
+    ];
+    let graph = BidirectedAdjacencyArray::<u8, PlainGfaNodeData, PlainGfaEdgeData>::new(
+        nodes.into(),
+        edges.into(),
+    );
+
+    assert_eq!(graph.node_sequence_length(1.into()), 10);
+    assert_eq!(graph.node_sequence_length(4.into()), 0);
+
+    // The path via N1 spells 5 + 8 + 3 = 16 characters, and the path via N2 and N4 spells 5 + 3 + 0 + 4 = 12.
+    let (length, path) = graph
+        .shortest_path_by_sequence_length(directed(0, true), directed(3, true))
+        .unwrap();
+    assert_eq!(length, 12);
+    assert_eq!(path.len(), 3);
+    let (length, path) = graph
+        .shortest_path_by_sequence_length(directed(3, false), directed(0, false))
+        .unwrap();
+    assert_eq!(length, 12);
+    assert_eq!(path.len(), 3);
+
+    let (length, path) = graph
+        .shortest_path_by_sequence_length(directed(1, true), directed(3, true))
+        .unwrap();
+    assert_eq!(length, 13);
+    assert_eq!(path.len(), 1);
+
+    assert_eq!(
+        graph.shortest_path_by_sequence_length(directed(1, true), directed(1, true)),
+        Some((10, Vec::new()))
+    );
+    assert_eq!(
+        graph.shortest_path_by_sequence_length(directed(3, true), directed(0, true)),
+        None
+    );
+}
+
+#[test]
+fn test_is_dag() {
+    assert!(example_graph().is_dag());
+
+    let nodes = vec![(), ()];
+    let edges = vec![BidirectedEdge::new(
+        directed(0, true),
+        directed(0, false),
+        0,
+    )];
+    let graph = BidirectedAdjacencyArray::<u8, (), u8>::new(nodes.clone().into(), edges.into());
+    assert!(graph.is_dag());
+
+    let edges = vec![BidirectedEdge::new(directed(0, true), directed(0, true), 0)];
+    let graph = BidirectedAdjacencyArray::<u8, (), u8>::new(nodes.clone().into(), edges.into());
+    assert!(!graph.is_dag());
+
+    let edges = vec![
+        BidirectedEdge::new(directed(0, true), directed(1, false), 0),
+        BidirectedEdge::new(directed(1, false), directed(0, true), 1),
+    ];
+    let graph = BidirectedAdjacencyArray::<u8, (), u8>::new(nodes.into(), edges.into());
+    assert!(!graph.is_dag());
+}
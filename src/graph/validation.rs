@@ -0,0 +1,50 @@
+use tagged_vec::TaggedVec;
+use thiserror::Error;
+
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge},
+    index::{EdgeIndex, GraphIndexInteger, NodeIndex},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EdgeEndpointError<IndexType> {
+    #[error("edge {0} has endpoint node {1}, but the graph has only {2} nodes")]
+    EndpointOutOfRange(EdgeIndex<IndexType>, NodeIndex<IndexType>, usize),
+}
+
+impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
+    BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>
+{
+    /// Like [`Self::new`], but returns an error instead of panicking if an edge has an endpoint that is not among the given nodes.
+    pub fn try_new(
+        nodes: TaggedVec<NodeIndex<IndexType>, NodeData>,
+        edges: TaggedVec<EdgeIndex<IndexType>, BidirectedEdge<IndexType, EdgeData>>,
+    ) -> Result<Self, EdgeEndpointError<IndexType>> {
+        Self::validate_edge_endpoints(nodes.len(), &edges)?;
+        Ok(Self::new(nodes, edges))
+    }
+
+    /// Checks that both endpoints of every edge are less than the given node count.
+    ///
+    /// [`Self::new`] panics on edges that fail this check, so this allows to reject manually or fuzzed input up front.
+    /// Returns an error for the first out-of-range endpoint, in edge order and checking `from` before `to`.
+    pub fn validate_edge_endpoints(
+        node_count: usize,
+        edges: &TaggedVec<EdgeIndex<IndexType>, BidirectedEdge<IndexType, EdgeData>>,
+    ) -> Result<(), EdgeEndpointError<IndexType>> {
+        for (edge_index, edge) in edges.iter() {
+            for node in [edge.from, edge.to] {
+                if node.into_usize() >= node_count {
+                    return Err(EdgeEndpointError::EndpointOutOfRange(
+                        edge_index, node, node_count,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use tagged_vec::TaggedVec;
+
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge, EdgeEndpointError},
+    test_util::directed,
+};
+
+#[test]
+fn test_validate_edge_endpoints() {
+    let edges: TaggedVec<_, _> = vec![
+        BidirectedEdge::new(directed(0, true), directed(1, false), ()),
+        BidirectedEdge::new(directed(1, true), directed(1, true), ()),
+    ]
+    .into();
+    assert_eq!(
+        BidirectedAdjacencyArray::<u8, (), ()>::validate_edge_endpoints(2, &edges),
+        Ok(())
+    );
+    assert_eq!(
+        BidirectedAdjacencyArray::<u8, (), ()>::validate_edge_endpoints(1, &edges),
+        Err(EdgeEndpointError::EndpointOutOfRange(0.into(), 1.into(), 1))
+    );
+    assert_eq!(
+        BidirectedAdjacencyArray::<u8, (), ()>::validate_edge_endpoints(0, &TaggedVec::new()),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_try_new() {
+    let edges = vec![
+        BidirectedEdge::new(directed(0, true), directed(1, false), ()),
+        BidirectedEdge::new(directed(1, true), directed(2, true), ()),
+    ];
+
+    let graph = BidirectedAdjacencyArray::<u8, (), ()>::try_new(
+        vec![(), (), ()].into(),
+        edges.clone().into(),
+    )
+    .unwrap();
+    assert_eq!(graph.edge_count(), 2);
+
+    assert_eq!(
+        BidirectedAdjacencyArray::<u8, (), ()>::try_new(vec![(), ()].into(), edges.into()).err(),
+        Some(EdgeEndpointError::EndpointOutOfRange(1.into(), 2.into(), 2))
+    );
+}
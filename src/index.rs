@@ -1,10 +1,19 @@
-use std::{
-    fmt::{Debug, Display},
-    hash::Hash,
+use alloc::{string::String, string::ToString};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    num::ParseIntError,
 };
 
 use num_traits::{Bounded, PrimInt};
 use optional_numeric_index::implement_generic_index;
+use thiserror::Error;
+
+use crate::graph::BidirectedAdjacencyArray;
+
+#[cfg(test)]
+mod tests;
 
 pub trait GraphIndexInteger:
     PrimInt + Bounded + Hash + Debug + Display + From<u8> + TryFrom<usize> + TryInto<usize>
@@ -19,9 +28,417 @@ impl<T: PrimInt + Bounded + Hash + Debug + Display + From<u8> + TryFrom<usize> +
 implement_generic_index!(pub NodeIndex, pub OptionalNodeIndex);
 implement_generic_index!(pub EdgeIndex, pub OptionalEdgeIndex);
 
-implement_generic_index!(pub DirectedNodeIndex, pub OptionalDirectedNodeIndex);
 implement_generic_index!(pub DirectedEdgeIndex, pub OptionalDirectedEdgeIndex);
 
+/// The index of a node together with its orientation.
+///
+/// The raw value is `2 * node` for the forward and `2 * node + 1` for the reverse orientation.
+/// Unlike the other index types, this is not generated by [`implement_generic_index`],
+/// because its [`Display`] implementation prints the bidirected node followed by its orientation, e.g. `3+` or `3-`.
+/// [`Debug`] prints the raw value.
+pub struct DirectedNodeIndex<IndexType>(IndexType);
+
+/// An optional [`DirectedNodeIndex`] that uses the maximum value of `IndexType` to represent `None`.
+pub struct OptionalDirectedNodeIndex<IndexType>(IndexType);
+
+impl<IndexType> DirectedNodeIndex<IndexType> {
+    pub fn new(value: IndexType) -> Self
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        assert_ne!(value, IndexType::max_value());
+        Self(value)
+    }
+
+    pub fn from_usize(value: usize) -> Self
+    where
+        IndexType: Bounded + Eq + Debug + TryFrom<usize>,
+    {
+        Self::new(
+            value
+                .try_into()
+                .ok()
+                .expect("index conversion from usize failed"),
+        )
+    }
+
+    pub fn into_usize(self) -> usize
+    where
+        IndexType: TryInto<usize>,
+    {
+        self.0
+            .try_into()
+            .ok()
+            .expect("index conversion to usize failed")
+    }
+
+    pub fn from_raw(value: IndexType) -> Self
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        Self::new(value)
+    }
+
+    pub fn into_raw(self) -> IndexType {
+        self.0
+    }
+}
+
+impl<IndexType> OptionalDirectedNodeIndex<IndexType> {
+    pub fn new_some(value: IndexType) -> Self
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        assert_ne!(value, IndexType::max_value());
+        Self(value)
+    }
+
+    pub fn new_none() -> Self
+    where
+        IndexType: Bounded,
+    {
+        Self(IndexType::max_value())
+    }
+
+    pub fn from_option(option: Option<DirectedNodeIndex<IndexType>>) -> Self
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        Self::from(option)
+    }
+
+    pub fn into_option(self) -> Option<DirectedNodeIndex<IndexType>>
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        self.into()
+    }
+
+    pub fn expect(self, message: &str) -> DirectedNodeIndex<IndexType>
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        self.into_option().expect(message)
+    }
+
+    pub fn unwrap(self) -> DirectedNodeIndex<IndexType>
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        self.into_option().unwrap()
+    }
+
+    pub fn unwrap_or(self, default: DirectedNodeIndex<IndexType>) -> DirectedNodeIndex<IndexType>
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        self.into_option().unwrap_or(default)
+    }
+
+    pub fn unwrap_or_else(
+        self,
+        default: impl FnOnce() -> DirectedNodeIndex<IndexType>,
+    ) -> DirectedNodeIndex<IndexType>
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        self.into_option().unwrap_or_else(default)
+    }
+
+    /// # Safety
+    ///
+    /// The index must not be `None`.
+    pub unsafe fn unwrap_unchecked(self) -> DirectedNodeIndex<IndexType>
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        unsafe { self.into_option().unwrap_unchecked() }
+    }
+
+    pub fn from_usize(value: usize) -> Self
+    where
+        IndexType: Bounded + Eq + Debug + TryFrom<usize>,
+    {
+        Self::from(Some(DirectedNodeIndex::from_usize(value)))
+    }
+
+    pub fn from_option_usize(value: Option<usize>) -> Self
+    where
+        IndexType: Bounded + Eq + Debug + TryFrom<usize>,
+    {
+        Self::from(value)
+    }
+
+    pub fn into_usize(self) -> Option<usize>
+    where
+        IndexType: Bounded + Eq + TryInto<usize>,
+    {
+        self.into()
+    }
+
+    pub fn from_raw(value: Option<IndexType>) -> Self
+    where
+        IndexType: Bounded + Eq + Debug,
+    {
+        match value {
+            Some(value) => Self::new_some(value),
+            None => Self::new_none(),
+        }
+    }
+
+    pub fn into_raw(self) -> Option<IndexType>
+    where
+        IndexType: Bounded + Eq,
+    {
+        if self.is_some() { Some(self.0) } else { None }
+    }
+
+    pub fn is_some(&self) -> bool
+    where
+        IndexType: Bounded + Eq,
+    {
+        self.0 != IndexType::max_value()
+    }
+
+    pub fn is_none(&self) -> bool
+    where
+        IndexType: Bounded + Eq,
+    {
+        self.0 == IndexType::max_value()
+    }
+}
+
+impl<IndexType: Bounded + Eq + Debug> From<Option<DirectedNodeIndex<IndexType>>>
+    for OptionalDirectedNodeIndex<IndexType>
+{
+    fn from(index: Option<DirectedNodeIndex<IndexType>>) -> Self {
+        match index {
+            Some(index) => Self::new_some(index.0),
+            None => Self::new_none(),
+        }
+    }
+}
+
+impl<IndexType: Bounded + Eq + Debug> From<DirectedNodeIndex<IndexType>>
+    for OptionalDirectedNodeIndex<IndexType>
+{
+    fn from(index: DirectedNodeIndex<IndexType>) -> Self {
+        Self::new_some(index.0)
+    }
+}
+
+impl<IndexType: Bounded + Eq + Debug> From<OptionalDirectedNodeIndex<IndexType>>
+    for Option<DirectedNodeIndex<IndexType>>
+{
+    fn from(optional_index: OptionalDirectedNodeIndex<IndexType>) -> Self {
+        if optional_index.is_some() {
+            Some(DirectedNodeIndex::new(optional_index.0))
+        } else {
+            None
+        }
+    }
+}
+
+impl<IndexType: Bounded + Eq + Debug + TryFrom<usize>> From<usize>
+    for DirectedNodeIndex<IndexType>
+{
+    fn from(value: usize) -> Self {
+        Self::from_usize(value)
+    }
+}
+
+impl<IndexType: Bounded + Eq + Debug + TryFrom<usize>> From<usize>
+    for OptionalDirectedNodeIndex<IndexType>
+{
+    fn from(value: usize) -> Self {
+        Self::from_usize(value)
+    }
+}
+
+impl<IndexType: Bounded + Eq + Debug + TryFrom<usize>> From<Option<usize>>
+    for OptionalDirectedNodeIndex<IndexType>
+{
+    fn from(value: Option<usize>) -> Self {
+        Self::from(value.map(DirectedNodeIndex::from_usize))
+    }
+}
+
+impl<IndexType: TryInto<usize>> From<DirectedNodeIndex<IndexType>> for usize {
+    fn from(value: DirectedNodeIndex<IndexType>) -> Self {
+        value.into_usize()
+    }
+}
+
+impl<IndexType: Bounded + Eq + TryInto<usize>> From<OptionalDirectedNodeIndex<IndexType>>
+    for Option<usize>
+{
+    fn from(index: OptionalDirectedNodeIndex<IndexType>) -> Self {
+        if index.is_some() {
+            Some(
+                index
+                    .0
+                    .try_into()
+                    .ok()
+                    .expect("index conversion to usize failed"),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<IndexType: Debug> Debug for DirectedNodeIndex<IndexType> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DirectedNodeIndex({:?})", self.0)
+    }
+}
+
+impl<IndexType: Bounded + Eq + Debug> Debug for OptionalDirectedNodeIndex<IndexType> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_some() {
+            write!(f, "OptionalDirectedNodeIndex({:?})", self.0)
+        } else {
+            write!(f, "OptionalDirectedNodeIndex(None)")
+        }
+    }
+}
+
+impl<IndexType: GraphIndexInteger> Display for DirectedNodeIndex<IndexType> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let sign = if self.is_forward() { '+' } else { '-' };
+        write!(f, "{}{sign}", self.into_bidirected())
+    }
+}
+
+impl<IndexType: GraphIndexInteger> Display for OptionalDirectedNodeIndex<IndexType> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.into_option() {
+            Some(index) => write!(f, "{index}"),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+impl<IndexType: Clone> Clone for DirectedNodeIndex<IndexType> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<IndexType: Clone> Clone for OptionalDirectedNodeIndex<IndexType> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<IndexType: Copy> Copy for DirectedNodeIndex<IndexType> {}
+
+impl<IndexType: Copy> Copy for OptionalDirectedNodeIndex<IndexType> {}
+
+impl<IndexType: PartialEq> PartialEq for DirectedNodeIndex<IndexType> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<IndexType: PartialEq> PartialEq for OptionalDirectedNodeIndex<IndexType> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<IndexType: Eq> Eq for DirectedNodeIndex<IndexType> {}
+
+impl<IndexType: Eq> Eq for OptionalDirectedNodeIndex<IndexType> {}
+
+impl<IndexType: PartialOrd> PartialOrd for DirectedNodeIndex<IndexType> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<IndexType: PartialOrd> PartialOrd for OptionalDirectedNodeIndex<IndexType> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<IndexType: Ord> Ord for DirectedNodeIndex<IndexType> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<IndexType: Ord> Ord for OptionalDirectedNodeIndex<IndexType> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<IndexType: Hash> Hash for DirectedNodeIndex<IndexType> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<IndexType: Hash> Hash for OptionalDirectedNodeIndex<IndexType> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseIndexError {
+    #[error("'{0}' is not a valid index: {1}")]
+    InvalidNumber(String, ParseIntError),
+
+    #[error("node index {0} is out of range for a graph with {1} nodes")]
+    NodeOutOfRange(usize, usize),
+
+    #[error("edge index {0} is out of range for a graph with {1} edges")]
+    EdgeOutOfRange(usize, usize),
+}
+
+impl<IndexType: GraphIndexInteger> NodeIndex<IndexType> {
+    /// Parses a decimal node index and checks that it exists in the given graph.
+    ///
+    /// Surrounding whitespace is ignored.
+    pub fn parse_checked<NodeData, EdgeData>(
+        s: &str,
+        graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    ) -> Result<Self, ParseIndexError> {
+        let index = parse_usize(s)?;
+        if index < graph.node_count() {
+            Ok(Self::from_usize(index))
+        } else {
+            Err(ParseIndexError::NodeOutOfRange(index, graph.node_count()))
+        }
+    }
+}
+
+impl<IndexType: GraphIndexInteger> EdgeIndex<IndexType> {
+    /// Parses a decimal edge index and checks that it exists in the given graph.
+    ///
+    /// Surrounding whitespace is ignored.
+    pub fn parse_checked<NodeData, EdgeData>(
+        s: &str,
+        graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    ) -> Result<Self, ParseIndexError> {
+        let index = parse_usize(s)?;
+        if index < graph.edge_count() {
+            Ok(Self::from_usize(index))
+        } else {
+            Err(ParseIndexError::EdgeOutOfRange(index, graph.edge_count()))
+        }
+    }
+}
+
+fn parse_usize(s: &str) -> Result<usize, ParseIndexError> {
+    s.trim()
+        .parse()
+        .map_err(|error| ParseIndexError::InvalidNumber(s.to_string(), error))
+}
+
 impl<IndexType: GraphIndexInteger> DirectedNodeIndex<IndexType> {
     pub fn from_bidirected(bidirected: NodeIndex<IndexType>, forward: bool) -> Self {
         let base = bidirected.0 * 2u8.into();
@@ -0,0 +1,12 @@
+use crate::index::{DirectedNodeIndex, NodeIndex};
+
+#[test]
+fn test_display_signed() {
+    let forward = DirectedNodeIndex::<u16>::from_bidirected(NodeIndex::from_usize(3), true);
+    let reverse = DirectedNodeIndex::<u16>::from_bidirected(NodeIndex::from_usize(3), false);
+
+    assert_eq!(forward.to_string(), "3+");
+    assert_eq!(reverse.to_string(), "3-");
+    assert_eq!(format!("{forward:?}"), "DirectedNodeIndex(6)");
+    assert_eq!(format!("{reverse:?}"), "DirectedNodeIndex(7)");
+}
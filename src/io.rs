@@ -1 +1,5 @@
+pub mod dot;
+pub mod fasta;
 pub mod gfa1;
+pub mod gfa2;
+pub mod sequence;
@@ -0,0 +1,94 @@
+//! Writing graphs in the DOT language of Graphviz.
+
+use std::{
+    borrow::Cow,
+    io::{BufWriter, Write},
+};
+
+use crate::{graph::BidirectedAdjacencyArray, index::GraphIndexInteger};
+
+#[cfg(test)]
+mod tests;
+
+/// Writes a graph as a Graphviz digraph.
+///
+/// Each bidirected node is drawn as a record with two ports, `start` on the left and `end` on the right,
+/// which are the sides of the node when reading its sequence in forward direction.
+/// A directed node `n+` is left via its `end` and entered via its `start`, and `n-` the other way around.
+/// Hence, each bidirected edge is drawn as a single arrow between the sides it connects,
+/// pointing in the direction in which it was constructed.
+/// Self loops are drawn as arrows between ports of the same node.
+///
+/// The labels of nodes and edges are given by the closures, and empty edge labels are omitted.
+pub fn write_dot<IndexType: GraphIndexInteger, NodeData, EdgeData>(
+    graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    writer: &mut impl Write,
+    node_label: impl Fn(&NodeData) -> Cow<'_, str>,
+    edge_label: impl Fn(&EdgeData) -> Cow<'_, str>,
+) -> Result<(), std::io::Error> {
+    let mut writer = BufWriter::new(writer);
+
+    writeln!(writer, "digraph {{")?;
+    writeln!(writer, "    rankdir=LR;")?;
+    writeln!(writer, "    node [shape=record];")?;
+
+    // Write nodes.
+    for (node, node_data) in graph.iter_node_data() {
+        writeln!(
+            writer,
+            "    n{node} [label=\"<start>|{}|<end>\"];",
+            escape_record_label(&node_label(node_data)),
+        )?;
+    }
+
+    // Write edges.
+    for edge in graph.iter_edge_views() {
+        let (from_node, from_forward) = edge.from_bidirected();
+        let (to_node, to_forward) = edge.to_bidirected();
+        let from_port = if from_forward { "end" } else { "start" };
+        let to_port = if to_forward { "start" } else { "end" };
+        write!(
+            writer,
+            "    n{from_node}:{from_port} -> n{to_node}:{to_port}"
+        )?;
+
+        let label = edge_label(edge.data());
+        if label.is_empty() {
+            writeln!(writer, ";")?;
+        } else {
+            writeln!(writer, " [label=\"{}\"];", escape_label(&label))?;
+        }
+    }
+
+    writeln!(writer, "}}")?;
+    writer.flush()
+}
+
+/// Escapes a string for use inside a quoted DOT label.
+fn escape_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for character in label.chars() {
+        match character {
+            '"' | '\\' => escaped.push('\\'),
+            '\n' => {
+                escaped.push_str("\\n");
+                continue;
+            }
+            _ => {}
+        }
+        escaped.push(character);
+    }
+    escaped
+}
+
+/// Escapes a string for use as a field of a record label, where some additional characters have special meaning.
+fn escape_record_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for character in escape_label(label).chars() {
+        if matches!(character, '{' | '}' | '|' | '<' | '>' | ' ') {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+}
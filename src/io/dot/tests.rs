@@ -0,0 +1,42 @@
+use std::borrow::Cow;
+
+use crate::{
+    io::dot::write_dot,
+    test_util::{directed, graph},
+};
+
+#[test]
+fn test_write_dot() {
+    let graph = graph(
+        vec!["N0", "a {b}|\"c\""],
+        vec![
+            (directed(0, true), directed(1, true), 3),
+            (directed(1, false), directed(0, true), 0),
+            (directed(1, true), directed(1, false), 5),
+        ],
+    );
+
+    let mut buffer = Vec::new();
+    write_dot(
+        &graph,
+        &mut buffer,
+        |node_data| Cow::Borrowed(*node_data),
+        |edge_data| match edge_data {
+            0 => Cow::Borrowed(""),
+            overlap => Cow::Owned(format!("{overlap}M")),
+        },
+    )
+    .unwrap();
+
+    let expected = r#"digraph {
+    rankdir=LR;
+    node [shape=record];
+    n0 [label="<start>|N0|<end>"];
+    n1 [label="<start>|a\ \{b\}\|\"c\"|<end>"];
+    n0:end -> n1:start [label="3M"];
+    n1:start -> n0:start;
+    n1:end -> n1:end [label="5M"];
+}
+"#;
+    assert_eq!(std::str::from_utf8(&buffer).unwrap(), expected);
+}
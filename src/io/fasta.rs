@@ -0,0 +1,134 @@
+//! Reading and writing the sequences of the nodes of graphs as FASTA.
+
+use std::{
+    borrow::Cow,
+    io::{BufRead, BufReader, BufWriter, Read, Write},
+};
+
+use tagged_vec::TaggedVec;
+
+use crate::{
+    graph::BidirectedAdjacencyArray,
+    index::{GraphIndexInteger, NodeIndex},
+    io::gfa1::PlainGfaNodeData,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(thiserror::Error, Debug)]
+pub enum FastaReadError {
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("sequence line {0} is not preceded by a header line")]
+    MissingHeader(usize),
+
+    #[error("the header in line {0} has no name")]
+    MissingName(usize),
+}
+
+/// Reads a FASTA file into a graph with one node per record and no edges.
+///
+/// The name of each node is the first whitespace-delimited token of its header,
+/// such that both plain headers like `>contig1` and headers with a description like `>contig1 length=500` are supported.
+/// Sequences may span multiple lines, and empty lines as well as comment lines starting with `;` are skipped.
+pub fn read_fasta<IndexType: GraphIndexInteger>(
+    reader: &mut impl Read,
+) -> Result<BidirectedAdjacencyArray<IndexType, PlainGfaNodeData, ()>, FastaReadError> {
+    let mut reader = BufReader::new(reader);
+    let mut nodes = TaggedVec::<NodeIndex<IndexType>, PlainGfaNodeData>::new();
+    let mut record: Option<(String, String)> = None;
+    let mut line = String::new();
+    let mut line_number = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        line_number += 1;
+
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('>') {
+            let name = header
+                .split_whitespace()
+                .next()
+                .ok_or(FastaReadError::MissingName(line_number))?;
+            if let Some((name, sequence)) = record.replace((name.to_string(), String::new())) {
+                nodes.push(PlainGfaNodeData::new(name, sequence));
+            }
+        } else {
+            let (_, sequence) = record
+                .as_mut()
+                .ok_or(FastaReadError::MissingHeader(line_number))?;
+            sequence.push_str(line.trim_start());
+        }
+    }
+
+    if let Some((name, sequence)) = record {
+        nodes.push(PlainGfaNodeData::new(name, sequence));
+    }
+
+    Ok(BidirectedAdjacencyArray::new(nodes, TaggedVec::new()))
+}
+
+/// The line width of the sequences written by [`write_fasta`].
+pub const DEFAULT_FASTA_LINE_WIDTH: usize = 80;
+
+/// Writes the sequence of each bidirected node as a FASTA record, wrapping sequence lines at [`DEFAULT_FASTA_LINE_WIDTH`].
+///
+/// See [`write_fasta_with_line_width`] for details.
+pub fn write_fasta<IndexType: GraphIndexInteger, NodeData, EdgeData>(
+    graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    writer: &mut impl Write,
+    name_of: impl Fn(&NodeData) -> Cow<'_, str>,
+    sequence_of: impl Fn(&NodeData) -> Option<Cow<'_, str>>,
+) -> Result<(), std::io::Error> {
+    write_fasta_with_line_width(
+        graph,
+        writer,
+        DEFAULT_FASTA_LINE_WIDTH,
+        name_of,
+        sequence_of,
+    )
+}
+
+/// Writes the sequence of each bidirected node as a FASTA record, in the order of the node indices.
+///
+/// The header of each record is the name returned by `name_of`, and the sequence is the forward sequence of the node.
+/// Nodes for which `sequence_of` returns `None` are skipped.
+/// The sequence is wrapped into lines of `line_width` characters, or written on a single line if `line_width` is zero.
+pub fn write_fasta_with_line_width<IndexType: GraphIndexInteger, NodeData, EdgeData>(
+    graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    writer: &mut impl Write,
+    line_width: usize,
+    name_of: impl Fn(&NodeData) -> Cow<'_, str>,
+    sequence_of: impl Fn(&NodeData) -> Option<Cow<'_, str>>,
+) -> Result<(), std::io::Error> {
+    let mut writer = BufWriter::new(writer);
+
+    for (_, node_data) in graph.iter_node_data() {
+        let Some(sequence) = sequence_of(node_data) else {
+            continue;
+        };
+
+        writeln!(writer, ">{}", name_of(node_data))?;
+        let sequence = sequence.as_bytes();
+        let line_width = if line_width == 0 {
+            sequence.len().max(1)
+        } else {
+            line_width
+        };
+        for line in sequence.chunks(line_width) {
+            writer.write_all(line)?;
+            writeln!(writer)?;
+        }
+    }
+
+    writer.flush()
+}
@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge},
+    index::DirectedNodeIndex,
+    io::{
+        fasta::{FastaReadError, read_fasta, write_fasta, write_fasta_with_line_width},
+        gfa1::{GfaNodeData, PlainGfaEdgeData, PlainGfaNodeData},
+    },
+};
+
+fn example_graph() -> BidirectedAdjacencyArray<u8, PlainGfaNodeData, PlainGfaEdgeData> {
+    let nodes = vec![
+        PlainGfaNodeData::new("N0", "ACGTACGTAC"),
+        PlainGfaNodeData::new_without_sequence("N1"),
+        PlainGfaNodeData::new("N2", "GGG"),
+        PlainGfaNodeData::new("N3", ""),
+    ];
+    let edges = vec![BidirectedEdge::new_gfa(
+        DirectedNodeIndex::from_bidirected(0.into(), true),
+        DirectedNodeIndex::from_bidirected(2.into(), false),
+        1,
+    )];
+    BidirectedAdjacencyArray::new(nodes.into(), edges.into())
+}
+
+fn fasta_string(
+    graph: &BidirectedAdjacencyArray<u8, PlainGfaNodeData, PlainGfaEdgeData>,
+    line_width: usize,
+) -> String {
+    let mut buffer = Vec::new();
+    write_fasta_with_line_width(
+        graph,
+        &mut buffer,
+        line_width,
+        |node_data| node_data.name(),
+        |node_data| node_data.sequence(),
+    )
+    .unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn test_write_fasta() {
+    let graph = example_graph();
+
+    assert_eq!(
+        fasta_string(&graph, 4),
+        ">N0\nACGT\nACGT\nAC\n>N2\nGGG\n>N3\n"
+    );
+    assert_eq!(
+        fasta_string(&graph, 5),
+        ">N0\nACGTA\nCGTAC\n>N2\nGGG\n>N3\n"
+    );
+    assert_eq!(fasta_string(&graph, 0), ">N0\nACGTACGTAC\n>N2\nGGG\n>N3\n");
+
+    let mut buffer = Vec::new();
+    write_fasta(
+        &graph,
+        &mut buffer,
+        |node_data| node_data.name(),
+        |node_data| node_data.sequence(),
+    )
+    .unwrap();
+    assert_eq!(fasta_string(&graph, 0).as_bytes(), buffer);
+
+    // Node data that does not implement `GfaNodeData`.
+    let graph = BidirectedAdjacencyArray::<u8, _, ()>::new(
+        vec![("a", "A".repeat(100))].into(),
+        Vec::new().into(),
+    );
+    let mut buffer = Vec::new();
+    write_fasta(
+        &graph,
+        &mut buffer,
+        |(name, _)| Cow::Borrowed(name),
+        |(_, sequence)| Some(Cow::Borrowed(sequence)),
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        format!(">a\n{}\n{}\n", "A".repeat(80), "A".repeat(20))
+    );
+}
+
+#[test]
+fn test_read_fasta() {
+    let fasta = ">N0 first contig\nACGT\r\nACGT\n\nAC\n;comment\n>N2\nGGG\n>N3\tempty\n";
+    let graph = read_fasta::<u8>(&mut fasta.as_bytes()).unwrap();
+
+    let expected_nodes = vec![
+        PlainGfaNodeData::new("N0", "ACGTACGTAC"),
+        PlainGfaNodeData::new("N2", "GGG"),
+        PlainGfaNodeData::new("N3", ""),
+    ];
+    let expected_graph =
+        BidirectedAdjacencyArray::<u8, _, ()>::new(expected_nodes.into(), Vec::new().into());
+    expected_graph.expect_equal(&graph);
+
+    // Writing and reading again yields the same graph.
+    let mut buffer = Vec::new();
+    write_fasta_with_line_width(
+        &graph,
+        &mut buffer,
+        3,
+        |node_data| node_data.name(),
+        |node_data| node_data.sequence(),
+    )
+    .unwrap();
+    read_fasta::<u8>(&mut buffer.as_slice())
+        .unwrap()
+        .expect_equal(&graph);
+
+    assert_eq!(
+        read_fasta::<u8>(&mut "".as_bytes()).unwrap().node_count(),
+        0
+    );
+    assert!(matches!(
+        read_fasta::<u8>(&mut "ACGT\n>N0\n".as_bytes()),
+        Err(FastaReadError::MissingHeader(1))
+    ));
+    assert!(matches!(
+        read_fasta::<u8>(&mut ">N0\nA\n> \nC\n".as_bytes()),
+        Err(FastaReadError::MissingName(3))
+    ));
+}
@@ -3,28 +3,25 @@ use std::{
     collections::HashMap,
     fmt::Debug,
     io::{BufRead, BufReader, BufWriter, Read, Write},
+    iter,
+    ops::{BitOr, BitOrAssign},
 };
 
 use log::warn;
 use tagged_vec::TaggedVec;
 
 use crate::{
-    graph::{BidirectedAdjacencyArray, BidirectedEdge},
+    graph::{BidirectedAdjacencyArray, BidirectedEdge, NodeSet},
     index::{EdgeIndex, GraphIndexInteger, NodeIndex},
+    io::gfa1::node_name::{GfaNodeName, NodeNameMap},
 };
 
+pub use crate::data::{GfaEdgeData, GfaNodeData};
+
+mod node_name;
 #[cfg(test)]
 mod tests;
 
-pub trait GfaNodeData {
-    fn name(&'_ self) -> Cow<'_, str>;
-    fn sequence(&'_ self) -> Cow<'_, str>;
-}
-
-pub trait GfaEdgeData {
-    fn overlap(&self) -> u16;
-}
-
 #[derive(thiserror::Error, Debug)]
 pub enum GfaReadError {
     #[error("I/O error: {0}")]
@@ -39,30 +36,217 @@ pub enum GfaReadError {
     #[error("an L line is missing the four fields specifying the edge endpoints")]
     LLineTooShort,
 
-    #[error("unknown node name '{0}' in an L line")]
+    #[error("unknown node name '{0}' in an edge line")]
     UnknownNodeName(String),
 
     #[error("unknown sign '{0}' in an L line")]
     UnknownGfaNodeSign(String),
+
+    #[error("a J line is missing the five fields specifying the jump endpoints and distance")]
+    JLineTooShort,
+
+    #[error("invalid distance '{0}' in a J line")]
+    InvalidJumpDistance(String),
+
+    #[error("duplicate link between '{0}' and '{1}'")]
+    DuplicateLink(String, String),
+
+    #[error("invalid value in tag '{0}'")]
+    InvalidTagValue(String),
+
+    #[error("overlap of length {0} does not fit into the edge data")]
+    OverlapTooLong(usize),
 }
 
 pub fn read_gfa1<IndexType: GraphIndexInteger>(
     reader: &mut impl Read,
 ) -> Result<BidirectedAdjacencyArray<IndexType, PlainGfaNodeData, PlainGfaEdgeData>, GfaReadError> {
-    let reader = BufReader::new(reader);
-    let mut node_name_to_node = HashMap::new();
-    let mut nodes = TaggedVec::<NodeIndex<IndexType>, _>::new();
+    read_gfa1_with_header(reader).map(|(graph, _)| graph)
+}
+
+/// Reads a graph from GFA1, also returning the tags of its header.
+///
+/// If there is no header, then the default header declaring version 1.0 is returned.
+#[allow(clippy::type_complexity)]
+pub fn read_gfa1_with_header<IndexType: GraphIndexInteger>(
+    reader: &mut impl Read,
+) -> Result<
+    (
+        BidirectedAdjacencyArray<IndexType, PlainGfaNodeData, PlainGfaEdgeData>,
+        GfaHeader,
+    ),
+    GfaReadError,
+> {
+    read_gfa1_with_jumps_and_progress(reader, &GfaReadOptions::default(), false, |_| {})
+        .map(|(graph, header, _)| (graph, header))
+}
+
+/// Reads a graph from GFA1, also returning the tags of its header and its `J` lines.
+///
+/// Jumps are not added to the graph as edges, since they do not denote adjacency.
+/// Unlike the other readers, which skip `J` lines with a warning, this returns an error
+/// if a `J` line is malformed or refers to an unknown segment.
+/// See [`read_gfa1_with_header`] for details on the header.
+#[allow(clippy::type_complexity)]
+pub fn read_gfa1_with_jumps<IndexType: GraphIndexInteger>(
+    reader: &mut impl Read,
+) -> Result<
+    (
+        BidirectedAdjacencyArray<IndexType, PlainGfaNodeData, PlainGfaEdgeData>,
+        GfaHeader,
+        Vec<GfaJump<IndexType>>,
+    ),
+    GfaReadError,
+> {
+    read_gfa1_with_jumps_and_progress(reader, &GfaReadOptions::default(), true, |_| {})
+}
+
+/// Options for [`read_gfa1_with_options`].
+///
+/// The default options produce the same graph as [`read_gfa1`].
+#[derive(Debug, Clone, Copy)]
+pub struct GfaReadOptions {
+    /// How to handle `L` lines that describe an edge that was already read.
+    pub duplicate_edge_policy: DuplicateEdgePolicy,
+
+    /// If true, segment names that are decimal numbers are stored as integers instead of strings.
+    ///
+    /// The names are looked up by integer as long as all segment names are numeric,
+    /// and by string once a non-numeric name is read.
+    /// The resulting graph is equal to the one read without this option,
+    /// and [`PlainGfaNodeData::numeric_id`] returns the integer name in both cases.
+    pub numeric_node_names: bool,
+
+    /// If true, `L` lines that are the reverse complement of an already read link are skipped.
+    ///
+    /// For example, if `L a + b + 0M` was read, then `L b - a - 0M` describes the same edge and is skipped,
+    /// keeping the data of the first link.
+    /// Links are compared by their [canonical orientation](BidirectedEdge::canonical).
+    /// Links listed twice in the same orientation are still handled by [`Self::duplicate_edge_policy`].
+    pub collapse_reverse_complement_links: bool,
+
+    /// The character between the fields of a line, see [`GfaWriteOptions::separator`].
+    pub separator: char,
+
+    /// If true, lines that do not contain [`Self::separator`] are split at spaces instead.
+    ///
+    /// This allows to read files whose lines were written with spaces instead of tabs,
+    /// but misreads lines that consist of a single field containing spaces.
+    pub lenient_separator: bool,
+}
+
+impl Default for GfaReadOptions {
+    fn default() -> Self {
+        Self {
+            duplicate_edge_policy: DuplicateEdgePolicy::default(),
+            numeric_node_names: false,
+            collapse_reverse_complement_links: false,
+            separator: '\t',
+            lenient_separator: false,
+        }
+    }
+}
+
+/// How to handle `L` lines that describe an edge that was already read.
+///
+/// Two links are duplicates if they have the same endpoints and orientations,
+/// either directly or in reverse-complemental orientation, see [`BidirectedEdge::canonical`].
+#[derive(Debug, Clone, Copy, Default)]
+pub enum DuplicateEdgePolicy {
+    /// Add duplicate links as parallel edges.
+    #[default]
+    Keep,
+
+    /// Return an error on the first duplicate link.
+    Error,
+
+    /// Combine the data of each duplicate link into the data of the first link with the given function.
+    Merge(fn(&mut PlainGfaEdgeData, &PlainGfaEdgeData)),
+}
+
+/// Reads a graph from GFA1 with the given options.
+pub fn read_gfa1_with_options<IndexType: GraphIndexInteger>(
+    reader: &mut impl Read,
+    options: &GfaReadOptions,
+) -> Result<BidirectedAdjacencyArray<IndexType, PlainGfaNodeData, PlainGfaEdgeData>, GfaReadError> {
+    read_gfa1_with_jumps_and_progress(reader, options, false, |_| {}).map(|(graph, _, _)| graph)
+}
+
+/// Reads the topology of a graph from GFA1, without storing the sequences and tags of the segments.
+///
+/// The nodes store only the name and the sequence length of each segment.
+/// The length is taken from the sequence, or from the `LN:i:` tag if the sequence is `*`.
+/// This needs much less memory than [`read_gfa1`] for graphs with long sequences,
+/// while still supporting all operations that do not need the sequences themselves.
+pub fn read_gfa1_topology_only<IndexType: GraphIndexInteger>(
+    reader: &mut impl Read,
+) -> Result<BidirectedAdjacencyArray<IndexType, TopologyGfaNodeData, PlainGfaEdgeData>, GfaReadError>
+{
+    read_gfa1_with_jumps_and_progress(reader, &GfaReadOptions::default(), false, |_| {})
+        .map(|(graph, _, _)| graph)
+}
+
+/// The number of lines after which [`read_gfa1_with_progress`] reports its progress.
+pub const GFA_PROGRESS_INTERVAL_LINES: u64 = 100_000;
+
+/// Reads a graph from GFA1, reporting the number of bytes read so far to `on_progress`.
+///
+/// The progress is reported every [`GFA_PROGRESS_INTERVAL_LINES`] lines, and once after the last line.
+pub fn read_gfa1_with_progress<IndexType: GraphIndexInteger>(
+    reader: &mut impl Read,
+    on_progress: impl FnMut(u64),
+) -> Result<BidirectedAdjacencyArray<IndexType, PlainGfaNodeData, PlainGfaEdgeData>, GfaReadError> {
+    read_gfa1_with_jumps_and_progress(reader, &GfaReadOptions::default(), false, on_progress)
+        .map(|(graph, _, _)| graph)
+}
+
+#[allow(clippy::type_complexity)]
+fn read_gfa1_with_jumps_and_progress<IndexType: GraphIndexInteger, NodeData: FromGfaSegment>(
+    reader: &mut impl Read,
+    options: &GfaReadOptions,
+    capture_jumps: bool,
+    mut on_progress: impl FnMut(u64),
+) -> Result<
+    (
+        BidirectedAdjacencyArray<IndexType, NodeData, PlainGfaEdgeData>,
+        GfaHeader,
+        Vec<GfaJump<IndexType>>,
+    ),
+    GfaReadError,
+> {
+    let mut reader = BufReader::new(reader);
+    let mut header = GfaHeader::default();
+    let mut node_name_to_node = NodeNameMap::new(options.numeric_node_names);
+    let mut nodes = TaggedVec::<NodeIndex<IndexType>, NodeData>::new();
     let mut edges = TaggedVec::<EdgeIndex<IndexType>, _>::new();
+    let mut jumps = Vec::new();
+    let mut canonical_edges = HashMap::new();
     let mut is_header_allowed = true;
+    let mut line = String::new();
+    let mut bytes_read = 0;
+    let mut lines_read = 0;
 
-    for line in reader.lines() {
-        let line = line?;
-        let line = line.trim().split('\t').collect::<Vec<_>>();
+    loop {
+        line.clear();
+        let line_length = reader.read_line(&mut line)?;
+        if line_length == 0 {
+            break;
+        }
+        bytes_read += line_length as u64;
+        lines_read += 1;
+        if lines_read % GFA_PROGRESS_INTERVAL_LINES == 0 {
+            on_progress(bytes_read);
+        }
+
+        let Some(line) = split_gfa_line(&line, options) else {
+            continue;
+        };
 
         match line[0] {
             "H" => {
                 if is_header_allowed {
-                    if line.get(1) != Some(&"VN:Z:1.0") {
+                    header = GfaHeader::parse(&line[1..]);
+                    if header.version.as_deref() != Some("1.0") {
                         warn!("Unsupported GFA version");
                     }
                 } else {
@@ -73,22 +257,21 @@ pub fn read_gfa1<IndexType: GraphIndexInteger>(
             "S" => {
                 let name = line
                     .get(1)
-                    .ok_or(GfaReadError::MissingSequenceNameInSLine)?
-                    .to_string();
-                let sequence = line.get(2).unwrap_or(&"").to_string();
-                let node = nodes.push(PlainGfaNodeData {
-                    name: name.clone(),
-                    sequence,
-                });
-                node_name_to_node.insert(name.clone(), node);
+                    .ok_or(GfaReadError::MissingSequenceNameInSLine)?;
+                let sequence = match line.get(2) {
+                    None | Some(&"*") => None,
+                    Some(sequence) => Some(*sequence),
+                };
+                let tags = line.get(3..).unwrap_or_default();
+                let name = node_name_to_node.insert(name, NodeIndex::from_usize(nodes.len()));
+                nodes.push(NodeData::from_gfa_segment(name, sequence, tags)?);
             }
 
             "L" => {
                 // Parse edge line.
                 let from_name = line.get(1).ok_or(GfaReadError::LLineTooShort)?;
                 let from = node_name_to_node
-                    .get(*from_name)
-                    .copied()
+                    .get(from_name)
                     .ok_or_else(|| GfaReadError::UnknownNodeName(from_name.to_string()))?;
                 let from_forward = match *line.get(2).ok_or(GfaReadError::LLineTooShort)? {
                     "+" => true,
@@ -97,26 +280,101 @@ pub fn read_gfa1<IndexType: GraphIndexInteger>(
                 };
                 let to_name = line.get(3).ok_or(GfaReadError::LLineTooShort)?;
                 let to = node_name_to_node
-                    .get(*to_name)
-                    .copied()
+                    .get(to_name)
                     .ok_or_else(|| GfaReadError::UnknownNodeName(to_name.to_string()))?;
                 let to_forward = match *line.get(4).ok_or(GfaReadError::LLineTooShort)? {
                     "+" => true,
                     "-" => false,
                     other => return Err(GfaReadError::UnknownGfaNodeSign(other.to_string())),
                 };
-                let overlap_str = line.get(5).unwrap_or(&"0M");
-                let overlap = overlap_str
-                    .trim_end_matches('M')
-                    .parse::<u16>()
-                    .unwrap_or(0);
-
-                edges.push(BidirectedEdge {
+                let overlap = match *line.get(5).unwrap_or(&"0M") {
+                    "*" => None,
+                    overlap => Some(overlap.trim_end_matches('M').parse::<u16>().unwrap_or(0)),
+                };
+                let edge = BidirectedEdge {
                     from,
                     from_forward,
                     to,
                     to_forward,
                     data: PlainGfaEdgeData { overlap },
+                };
+
+                if let DuplicateEdgePolicy::Keep = options.duplicate_edge_policy
+                    && !options.collapse_reverse_complement_links
+                {
+                    edges.push(edge);
+                } else {
+                    let canonical_edge = BidirectedEdge {
+                        from,
+                        from_forward,
+                        to,
+                        to_forward,
+                        data: (),
+                    }
+                    .canonical();
+                    let existing_edge = canonical_edges.get(&canonical_edge).copied();
+                    let is_redundant_reverse_complement = options.collapse_reverse_complement_links
+                        && existing_edge.is_some_and(|existing_edge| {
+                            !edges[existing_edge].structural_eq(&edge)
+                        });
+                    match (existing_edge, options.duplicate_edge_policy) {
+                        _ if is_redundant_reverse_complement => {
+                            // The link was already read in the other orientation.
+                        }
+                        (Some(_), DuplicateEdgePolicy::Keep) => {
+                            edges.push(edge);
+                        }
+                        (Some(_), DuplicateEdgePolicy::Error) => {
+                            return Err(GfaReadError::DuplicateLink(
+                                from_name.to_string(),
+                                to_name.to_string(),
+                            ));
+                        }
+                        (Some(existing_edge), DuplicateEdgePolicy::Merge(merge)) => {
+                            let existing_edge: &mut BidirectedEdge<_, _> =
+                                &mut edges[existing_edge];
+                            merge(&mut existing_edge.data, &edge.data);
+                        }
+                        _ => {
+                            canonical_edges.insert(canonical_edge, edges.push(edge));
+                        }
+                    }
+                }
+            }
+
+            "J" if capture_jumps => {
+                let from_name = line.get(1).ok_or(GfaReadError::JLineTooShort)?;
+                let from = node_name_to_node
+                    .get(from_name)
+                    .ok_or_else(|| GfaReadError::UnknownNodeName(from_name.to_string()))?;
+                let from_forward =
+                    parse_gfa_node_sign(line.get(2).ok_or(GfaReadError::JLineTooShort)?)? == '+';
+                let to_name = line.get(3).ok_or(GfaReadError::JLineTooShort)?;
+                let to = node_name_to_node
+                    .get(to_name)
+                    .ok_or_else(|| GfaReadError::UnknownNodeName(to_name.to_string()))?;
+                let to_forward =
+                    parse_gfa_node_sign(line.get(4).ok_or(GfaReadError::JLineTooShort)?)? == '+';
+                let distance =
+                    match *line.get(5).ok_or(GfaReadError::JLineTooShort)? {
+                        "*" => None,
+                        distance => Some(distance.parse().map_err(|_| {
+                            GfaReadError::InvalidJumpDistance(distance.to_string())
+                        })?),
+                    };
+                let tags = line[6..]
+                    .iter()
+                    .filter(|tag| !tag.is_empty())
+                    .map(|tag| tag.to_string())
+                    .collect();
+
+                jumps.push(GfaJump {
+                    from,
+                    from_forward,
+                    to,
+                    to_forward,
+                    distance,
+                    tags,
                 });
             }
 
@@ -128,93 +386,871 @@ pub fn read_gfa1<IndexType: GraphIndexInteger>(
         is_header_allowed = false;
     }
 
-    Ok(BidirectedAdjacencyArray::new(nodes, edges))
+    on_progress(bytes_read);
+    Ok((BidirectedAdjacencyArray::new(nodes, edges), header, jumps))
+}
+
+/// A `J` line of a GFA file, i.e. a long-range jump between two segments.
+///
+/// The orientations have the same meaning as in [`BidirectedEdge`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GfaJump<IndexType> {
+    pub from: NodeIndex<IndexType>,
+    pub from_forward: bool,
+    pub to: NodeIndex<IndexType>,
+    pub to_forward: bool,
+    /// The estimated distance between the segments, or `None` if it is unknown, i.e. given as `*`.
+    pub distance: Option<i64>,
+    /// The optional tags of the jump, verbatim.
+    pub tags: Vec<String>,
+}
+
+/// Reads a stream of concatenated GFA1 files, yielding one graph per file.
+///
+/// Each `H` line starts a new graph, and lines before the first `H` line form a graph without header.
+/// Node names are only resolved within the graph they belong to.
+/// Blank records are skipped, and reading stops after the first error.
+pub fn read_gfa1_multi<IndexType: GraphIndexInteger>(
+    reader: &mut impl Read,
+) -> impl Iterator<
+    Item = Result<
+        BidirectedAdjacencyArray<IndexType, PlainGfaNodeData, PlainGfaEdgeData>,
+        GfaReadError,
+    >,
+> {
+    let mut reader = BufReader::new(reader);
+    let mut next_header: Option<String> = None;
+    let mut is_done = false;
+
+    iter::from_fn(move || {
+        while !is_done {
+            let mut record = next_header.take().unwrap_or_default();
+            let mut line = String::new();
+            loop {
+                line.clear();
+                match reader.read_line(&mut line) {
+                    Ok(0) => {
+                        is_done = true;
+                        break;
+                    }
+                    Ok(_) => {}
+                    Err(error) => {
+                        is_done = true;
+                        return Some(Err(error.into()));
+                    }
+                }
+
+                if split_gfa_line(&line, &GfaReadOptions::default())
+                    .is_some_and(|fields| fields[0] == "H")
+                    && !record.is_empty()
+                {
+                    next_header = Some(line.clone());
+                    break;
+                }
+                record.push_str(&line);
+            }
+
+            if !record.trim().is_empty() {
+                let result = read_gfa1(&mut record.as_bytes());
+                is_done |= result.is_err();
+                return Some(result);
+            }
+        }
+
+        None
+    })
+}
+
+/// The contents of the `H` line of a GFA file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GfaHeader {
+    /// The version given by the `VN:Z:` tag, if any.
+    pub version: Option<String>,
+
+    /// All other tags, verbatim, e.g. `PN:Z:vg`.
+    pub tags: Vec<String>,
+}
+
+impl GfaHeader {
+    fn parse(fields: &[&str]) -> Self {
+        let mut version = None;
+        let mut tags = Vec::new();
+        for field in fields {
+            if let Some(field_version) = field.strip_prefix("VN:Z:") {
+                version = Some(field_version.to_string());
+            } else if !field.is_empty() {
+                tags.push(field.to_string());
+            }
+        }
+        Self { version, tags }
+    }
+}
+
+impl Default for GfaHeader {
+    /// Returns a header declaring version 1.0 without further tags.
+    fn default() -> Self {
+        Self {
+            version: Some("1.0".to_string()),
+            tags: Vec::new(),
+        }
+    }
+}
+
+/// Filters a GFA1 stream record by record without building a graph.
+///
+/// Segments are kept if `keep_segment(name, sequence)` returns `true`.
+/// Links are kept if both their endpoints were kept and `keep_link(from_name, from_sign, to_name, to_sign)` returns `true`.
+/// Kept records are written through unchanged, and the header is always written through.
+///
+/// Only the names of the segments seen so far are held in memory, so links must appear after the segments they reference.
+pub fn filter_gfa1_stream(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+    keep_segment: impl Fn(&str, &str) -> bool,
+    keep_link: impl Fn(&str, char, &str, char) -> bool,
+) -> Result<(), GfaReadError> {
+    let reader = BufReader::new(reader);
+    let mut writer = BufWriter::new(writer);
+    let mut segment_is_kept = HashMap::new();
+    let mut is_header_allowed = true;
+
+    for raw_line in reader.lines() {
+        let raw_line = raw_line?;
+        let Some(line) = split_gfa_line(&raw_line, &GfaReadOptions::default()) else {
+            continue;
+        };
+        let raw_line = raw_line.trim_end();
+
+        match line[0] {
+            "H" => {
+                if is_header_allowed {
+                    writeln!(writer, "{raw_line}")?;
+                } else {
+                    return Err(GfaReadError::WronglyPositionedHeader);
+                }
+            }
+
+            "S" => {
+                let name = line
+                    .get(1)
+                    .ok_or(GfaReadError::MissingSequenceNameInSLine)?;
+                let sequence = line.get(2).unwrap_or(&"");
+                let is_kept = keep_segment(name, sequence);
+                segment_is_kept.insert(name.to_string(), is_kept);
+
+                if is_kept {
+                    writeln!(writer, "{raw_line}")?;
+                }
+            }
+
+            "L" => {
+                let from_name = line.get(1).ok_or(GfaReadError::LLineTooShort)?;
+                let from_sign =
+                    parse_gfa_node_sign(line.get(2).ok_or(GfaReadError::LLineTooShort)?)?;
+                let to_name = line.get(3).ok_or(GfaReadError::LLineTooShort)?;
+                let to_sign = parse_gfa_node_sign(line.get(4).ok_or(GfaReadError::LLineTooShort)?)?;
+
+                let from_is_kept = segment_is_kept
+                    .get(*from_name)
+                    .copied()
+                    .ok_or_else(|| GfaReadError::UnknownNodeName(from_name.to_string()))?;
+                let to_is_kept = segment_is_kept
+                    .get(*to_name)
+                    .copied()
+                    .ok_or_else(|| GfaReadError::UnknownNodeName(to_name.to_string()))?;
+
+                if from_is_kept && to_is_kept && keep_link(from_name, from_sign, to_name, to_sign) {
+                    writeln!(writer, "{raw_line}")?;
+                }
+            }
+
+            other => {
+                warn!("Unsupported GFA line type: {}", other);
+            }
+        }
+
+        is_header_allowed = false;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Splits a GFA line into its fields at the [separator](GfaReadOptions::separator) of the given options.
+///
+/// If the options are [lenient](GfaReadOptions::lenient_separator), lines without the separator are split at spaces instead.
+/// Whitespace around each field, including carriage returns, is removed.
+/// Empty fields are kept such that the column positions do not shift.
+/// Returns `None` if the line is blank.
+fn split_gfa_line<'line>(line: &'line str, options: &GfaReadOptions) -> Option<Vec<&'line str>> {
+    if line.trim().is_empty() {
+        None
+    } else if options.lenient_separator && !line.contains(options.separator) {
+        Some(line.split(' ').map(str::trim).collect())
+    } else {
+        Some(line.split(options.separator).map(str::trim).collect())
+    }
+}
+
+fn parse_gfa_node_sign(sign: &str) -> Result<char, GfaReadError> {
+    match sign {
+        "+" => Ok('+'),
+        "-" => Ok('-'),
+        other => Err(GfaReadError::UnknownGfaNodeSign(other.to_string())),
+    }
 }
 
 pub fn write_gfa1<IndexType: GraphIndexInteger, NodeData: GfaNodeData, EdgeData: GfaEdgeData>(
     graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
     writer: &mut impl Write,
 ) -> Result<(), std::io::Error> {
-    let mut writer = BufWriter::new(writer);
+    write_gfa1_with_options(graph, writer, &GfaWriteOptions::default())
+}
+
+/// Options for [`write_gfa1_with_options`].
+///
+/// The default options produce the same output as [`write_gfa1`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GfaWriteOptions {
+    /// If true, the `H` line is written.
+    pub emit_header: bool,
+
+    /// The contents of the `H` line.
+    pub header: GfaHeader,
+
+    /// The character written between the fields of a line.
+    pub separator: char,
+}
+
+impl Default for GfaWriteOptions {
+    fn default() -> Self {
+        Self {
+            emit_header: true,
+            header: GfaHeader::default(),
+            separator: '\t',
+        }
+    }
+}
+
+pub fn write_gfa1_with_options<
+    IndexType: GraphIndexInteger,
+    NodeData: GfaNodeData,
+    EdgeData: GfaEdgeData,
+>(
+    graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    writer: &mut impl Write,
+    options: &GfaWriteOptions,
+) -> Result<(), std::io::Error> {
+    write_gfa1_with_jumps(graph, &[], writer, options)
+}
+
+/// Writes a graph to GFA1 like [`write_gfa1_with_options`], followed by the given jumps as `J` lines.
+pub fn write_gfa1_with_jumps<
+    IndexType: GraphIndexInteger,
+    NodeData: GfaNodeData,
+    EdgeData: GfaEdgeData,
+>(
+    graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    jumps: &[GfaJump<IndexType>],
+    writer: &mut impl Write,
+    options: &GfaWriteOptions,
+) -> Result<(), std::io::Error> {
+    write_gfa1_subgraph(
+        graph,
+        jumps,
+        writer,
+        options,
+        GfaLineTypes::ALL,
+        |_| true,
+        |_| true,
+    )
+}
+
+/// A set of GFA1 line types, combined with `|`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GfaLineTypes(u8);
+
+impl GfaLineTypes {
+    pub const NONE: Self = Self(0);
+    /// The `H` line.
+    pub const HEADER: Self = Self(1);
+    /// The `S` lines.
+    pub const SEGMENTS: Self = Self(1 << 1);
+    /// The `L` lines.
+    pub const LINKS: Self = Self(1 << 2);
+    /// The `J` lines.
+    pub const JUMPS: Self = Self(1 << 3);
+    pub const ALL: Self = Self(Self::HEADER.0 | Self::SEGMENTS.0 | Self::LINKS.0 | Self::JUMPS.0);
+
+    /// Returns true if all line types of `other` are in this set.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for GfaLineTypes {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for GfaLineTypes {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+/// Writes only the lines of the given types of a graph to GFA1.
+///
+/// The written lines are the same as the corresponding lines written by [`write_gfa1`].
+/// For example, [`GfaLineTypes::SEGMENTS`] writes a dump of the nodes,
+/// and [`GfaLineTypes::LINKS`] writes only the topology.
+pub fn write_gfa1_lines<
+    IndexType: GraphIndexInteger,
+    NodeData: GfaNodeData,
+    EdgeData: GfaEdgeData,
+>(
+    graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    writer: &mut impl Write,
+    line_types: GfaLineTypes,
+) -> Result<(), std::io::Error> {
+    write_gfa1_subgraph(
+        graph,
+        &[],
+        writer,
+        &GfaWriteOptions::default(),
+        line_types,
+        |_| true,
+        |_| true,
+    )
+}
+
+/// Writes the nodes and edges of a graph that fulfil the given predicates to GFA1.
+///
+/// An edge is only written if both its endpoints are written, such that the output contains no links to missing segments.
+/// Otherwise, the output is the same as that of [`write_gfa1`].
+pub fn write_gfa1_filtered<
+    IndexType: GraphIndexInteger,
+    NodeData: GfaNodeData,
+    EdgeData: GfaEdgeData,
+>(
+    graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    writer: &mut impl Write,
+    keep_edge: impl Fn(&EdgeData) -> bool,
+    keep_node: impl Fn(&NodeData) -> bool,
+) -> Result<(), std::io::Error> {
+    write_gfa1_subgraph(
+        graph,
+        &[],
+        writer,
+        &GfaWriteOptions::default(),
+        GfaLineTypes::ALL,
+        keep_node,
+        keep_edge,
+    )
+}
+
+/// Writes a graph to GFA1 like [`write_gfa1`], extracting the GFA fields with the given closures.
+///
+/// This allows writing graphs whose node and edge data do not implement [`GfaNodeData`] and [`GfaEdgeData`].
+/// `name_of` must return a distinct name for each node, and `sequence_of` returns `None` for a missing sequence.
+/// No tags are written.
+pub fn write_gfa1_with<IndexType: GraphIndexInteger, NodeData, EdgeData>(
+    graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    writer: &mut impl Write,
+    name_of: impl Fn(&NodeData) -> Cow<'_, str>,
+    sequence_of: impl Fn(&NodeData) -> Option<Cow<'_, str>>,
+    overlap_of: impl Fn(&EdgeData) -> u16,
+) -> Result<(), std::io::Error> {
+    let mut writer = GfaStreamWriter::new(writer);
+    writer.write_header(&GfaHeader::default())?;
+
+    for node_data in graph.iter_node_data().map(|(_, node_data)| node_data) {
+        writer.write_segment(&name_of(node_data), sequence_of(node_data).as_deref(), &[])?;
+    }
+
+    for edge in graph.iter_edge_views() {
+        let (from_node, from_forward) = edge.from_bidirected();
+        let (to_node, to_forward) = edge.to_bidirected();
+        writer.write_link(
+            &name_of(graph.node_data(from_node)),
+            from_forward,
+            &name_of(graph.node_data(to_node)),
+            to_forward,
+            Some(overlap_of(edge.data())),
+            &[],
+        )?;
+    }
+
+    writer.finish()
+}
+
+fn write_gfa1_subgraph<
+    IndexType: GraphIndexInteger,
+    NodeData: GfaNodeData,
+    EdgeData: GfaEdgeData,
+>(
+    graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    jumps: &[GfaJump<IndexType>],
+    writer: &mut impl Write,
+    options: &GfaWriteOptions,
+    line_types: GfaLineTypes,
+    keep_node: impl Fn(&NodeData) -> bool,
+    keep_edge: impl Fn(&EdgeData) -> bool,
+) -> Result<(), std::io::Error> {
+    let mut writer = GfaStreamWriter::with_separator(writer, options.separator);
 
     // Write header.
-    writeln!(writer, "H\tVN:Z:1.0")?;
+    if options.emit_header && line_types.contains(GfaLineTypes::HEADER) {
+        writer.write_header(&options.header)?;
+    }
 
     // Write nodes.
-    for node in graph.iter_nodes() {
+    let mut kept_nodes = NodeSet::new(graph.node_count());
+    for node in graph.iter_nodes_where(keep_node) {
+        kept_nodes.insert(node);
+        if !line_types.contains(GfaLineTypes::SEGMENTS) {
+            continue;
+        }
         let node_data = graph.node_data(node);
-        writeln!(writer, "S\t{}\t{}", node_data.name(), node_data.sequence())?;
+        let tags = node_data.tags();
+        let tags = tags.iter().map(String::as_str).collect::<Vec<_>>();
+        writer.write_segment(&node_data.name(), node_data.sequence().as_deref(), &tags)?;
     }
 
     // Write edges.
-    for edge in graph.iter_edges() {
-        let edge_data = graph.edge(edge);
-
-        let from_node_name = graph.node_data(edge_data.from().into_bidirected()).name();
-        let to_node_name = graph.node_data(edge_data.to().into_bidirected()).name();
+    let edges = graph
+        .iter_edges_where(keep_edge)
+        .filter(|_| line_types.contains(GfaLineTypes::LINKS));
+    for edge in edges {
+        let edge = graph.edge(edge);
+        let (from_node, from_forward) = edge.from_bidirected();
+        let (to_node, to_forward) = edge.to_bidirected();
+        if !kept_nodes.contains(from_node) || !kept_nodes.contains(to_node) {
+            continue;
+        }
 
         // In mathematical notation, traversing an edge from a to b means using edge (a, \hat{b}).
         // But in GFA1, this means using edge (a, b), where both signs are unchanged.
-        let from_node_sign = if edge_data.from().is_forward() {
-            "+"
-        } else {
-            "-"
-        };
-        let to_node_sign = if edge_data.to().is_forward() {
-            "+"
-        } else {
-            "-"
-        };
+        writer.write_link(
+            &graph.node_data(from_node).name(),
+            from_forward,
+            &graph.node_data(to_node).name(),
+            to_forward,
+            edge.data()
+                .is_overlap_specified()
+                .then(|| edge.data().overlap()),
+            &[],
+        )?;
+    }
 
-        let overlap = edge_data.data().overlap();
+    // Write jumps.
+    let jumps = jumps
+        .iter()
+        .filter(|_| line_types.contains(GfaLineTypes::JUMPS));
+    for jump in jumps {
+        if !kept_nodes.contains(jump.from) || !kept_nodes.contains(jump.to) {
+            continue;
+        }
 
-        writeln!(
-            writer,
-            "L\t{from_node_name}\t{from_node_sign}\t{to_node_name}\t{to_node_sign}\t{overlap}M",
+        let tags = jump.tags.iter().map(String::as_str).collect::<Vec<_>>();
+        writer.write_jump(
+            &graph.node_data(jump.from).name(),
+            jump.from_forward,
+            &graph.node_data(jump.to).name(),
+            jump.to_forward,
+            jump.distance,
+            &tags,
         )?;
     }
 
-    Ok(())
+    writer.finish()
+}
+
+/// Writes GFA1 records one by one, without building a graph first.
+///
+/// Writing the same records as [`write_gfa1`] in the same order produces identical output.
+pub struct GfaStreamWriter<Writer: Write> {
+    writer: BufWriter<Writer>,
+    separator: char,
+}
+
+impl<Writer: Write> GfaStreamWriter<Writer> {
+    /// Creates a writer that separates fields with tabs.
+    pub fn new(writer: Writer) -> Self {
+        Self::with_separator(writer, '\t')
+    }
+
+    /// Creates a writer that separates fields with the given character.
+    pub fn with_separator(writer: Writer, separator: char) -> Self {
+        Self {
+            writer: BufWriter::new(writer),
+            separator,
+        }
+    }
+
+    /// Writes an `H` line with the version and tags of the given header.
+    ///
+    /// Use [`GfaHeader::default`] to declare GFA version 1.0.
+    pub fn write_header(&mut self, header: &GfaHeader) -> Result<(), std::io::Error> {
+        let separator = self.separator;
+        write!(self.writer, "H")?;
+        if let Some(version) = &header.version {
+            write!(self.writer, "{separator}VN:Z:{version}")?;
+        }
+        let tags = header.tags.iter().map(String::as_str).collect::<Vec<_>>();
+        self.write_tags(&tags)
+    }
+
+    /// Writes an `S` line.
+    ///
+    /// A missing sequence is written as `*`.
+    /// The tags are written verbatim after the sequence, e.g. `KC:i:5`.
+    pub fn write_segment(
+        &mut self,
+        name: &str,
+        sequence: Option<&str>,
+        tags: &[&str],
+    ) -> Result<(), std::io::Error> {
+        let separator = self.separator;
+        let sequence = sequence.unwrap_or("*");
+        write!(self.writer, "S{separator}{name}{separator}{sequence}")?;
+        self.write_tags(tags)
+    }
+
+    /// Writes an `L` line.
+    ///
+    /// The orientations have the same meaning as in [`BidirectedEdge`].
+    /// An unspecified overlap is written as `*`.
+    /// The tags are written verbatim after the overlap.
+    pub fn write_link(
+        &mut self,
+        from_name: &str,
+        from_forward: bool,
+        to_name: &str,
+        to_forward: bool,
+        overlap: Option<u16>,
+        tags: &[&str],
+    ) -> Result<(), std::io::Error> {
+        let separator = self.separator;
+        let from_sign = if from_forward { '+' } else { '-' };
+        let to_sign = if to_forward { '+' } else { '-' };
+        let overlap = overlap.map_or_else(|| "*".to_string(), |overlap| format!("{overlap}M"));
+        write!(
+            self.writer,
+            "L{separator}{from_name}{separator}{from_sign}{separator}{to_name}{separator}{to_sign}{separator}{overlap}",
+        )?;
+        self.write_tags(tags)
+    }
+
+    /// Writes a `J` line.
+    ///
+    /// The orientations have the same meaning as in [`BidirectedEdge`].
+    /// An unknown distance is written as `*`.
+    /// The tags are written verbatim after the distance.
+    pub fn write_jump(
+        &mut self,
+        from_name: &str,
+        from_forward: bool,
+        to_name: &str,
+        to_forward: bool,
+        distance: Option<i64>,
+        tags: &[&str],
+    ) -> Result<(), std::io::Error> {
+        let separator = self.separator;
+        let from_sign = if from_forward { '+' } else { '-' };
+        let to_sign = if to_forward { '+' } else { '-' };
+        let distance = distance.map_or_else(|| "*".to_string(), |distance| distance.to_string());
+        write!(
+            self.writer,
+            "J{separator}{from_name}{separator}{from_sign}{separator}{to_name}{separator}{to_sign}{separator}{distance}",
+        )?;
+        self.write_tags(tags)
+    }
+
+    /// Flushes all buffered records.
+    pub fn finish(mut self) -> Result<(), std::io::Error> {
+        self.writer.flush()
+    }
+
+    fn write_tags(&mut self, tags: &[&str]) -> Result<(), std::io::Error> {
+        for tag in tags {
+            write!(self.writer, "{}{tag}", self.separator)?;
+        }
+        writeln!(self.writer)
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct PlainGfaNodeData {
-    name: String,
-    sequence: String,
+    name: GfaNodeName,
+    sequence: Option<String>,
+    /// The length of the segment if its sequence is not stored but its length is known.
+    length: Option<usize>,
+    /// The `RC:i:` tag.
+    read_count: Option<u64>,
+    /// The `FC:i:` tag.
+    fragment_count: Option<u64>,
+    /// The `KC:i:` tag.
+    kmer_count: Option<u64>,
+    /// All other tags, verbatim.
+    tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct PlainGfaEdgeData {
-    overlap: u16,
+    /// The length of the overlap, or `None` if it is given as `*`.
+    overlap: Option<u16>,
 }
 
 impl PlainGfaNodeData {
     pub fn new(name: impl ToString, sequence: impl ToString) -> Self {
         Self {
-            name: name.to_string(),
-            sequence: sequence.to_string(),
+            name: GfaNodeName::Text(name.to_string()),
+            sequence: Some(sequence.to_string()),
+            ..Default::default()
         }
     }
+
+    /// Creates node data for a segment whose sequence is not stored, i.e. given as `*`.
+    pub fn new_without_sequence(name: impl ToString) -> Self {
+        Self {
+            name: GfaNodeName::Text(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Creates node data for a segment whose sequence is not stored, but whose length is known.
+    pub fn new_with_length(name: impl ToString, length: usize) -> Self {
+        Self {
+            name: GfaNodeName::Text(name.to_string()),
+            length: Some(length),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the name as an integer if it is a decimal number without sign or leading zeros.
+    pub fn numeric_id(&self) -> Option<u64> {
+        self.name.numeric_id()
+    }
+
+    /// Returns the read count given by the `RC:i:` tag.
+    pub fn read_count(&self) -> Option<u64> {
+        self.read_count
+    }
+
+    /// Returns the fragment count given by the `FC:i:` tag.
+    pub fn fragment_count(&self) -> Option<u64> {
+        self.fragment_count
+    }
+
+    /// Returns the k-mer count given by the `KC:i:` tag.
+    pub fn kmer_count(&self) -> Option<u64> {
+        self.kmer_count
+    }
+
+    pub fn set_read_count(&mut self, read_count: Option<u64>) {
+        self.read_count = read_count;
+    }
+
+    pub fn set_fragment_count(&mut self, fragment_count: Option<u64>) {
+        self.fragment_count = fragment_count;
+    }
+
+    pub fn set_kmer_count(&mut self, kmer_count: Option<u64>) {
+        self.kmer_count = kmer_count;
+    }
+
+    /// Returns the tags that are not stored in a typed field, verbatim.
+    pub fn other_tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Stores the given tag in its typed field, or verbatim if it has none.
+    ///
+    /// The `LN:i:` tag is stored as the length only if the sequence is not stored.
+    fn parse_tag(&mut self, tag: &str) -> Result<(), GfaReadError> {
+        if let (Some(length), None) = (tag.strip_prefix("LN:i:"), &self.sequence) {
+            self.length = Some(
+                length
+                    .parse()
+                    .map_err(|_| GfaReadError::InvalidTagValue(tag.to_string()))?,
+            );
+            return Ok(());
+        }
+
+        let field = match tag.get(..5) {
+            Some("RC:i:") => &mut self.read_count,
+            Some("FC:i:") => &mut self.fragment_count,
+            Some("KC:i:") => &mut self.kmer_count,
+            _ => {
+                self.tags.push(tag.to_string());
+                return Ok(());
+            }
+        };
+
+        *field = Some(
+            tag[5..]
+                .parse()
+                .map_err(|_| GfaReadError::InvalidTagValue(tag.to_string()))?,
+        );
+        Ok(())
+    }
 }
 
 impl GfaNodeData for PlainGfaNodeData {
     fn name(&'_ self) -> Cow<'_, str> {
-        Cow::Borrowed(&self.name)
+        self.name.as_str()
+    }
+
+    fn sequence(&'_ self) -> Option<Cow<'_, str>> {
+        self.sequence.as_deref().map(Cow::Borrowed)
     }
 
-    fn sequence(&'_ self) -> Cow<'_, str> {
-        Cow::Borrowed(&self.sequence)
+    fn sequence_length(&self) -> Option<usize> {
+        self.sequence.as_ref().map(String::len).or(self.length)
+    }
+
+    /// Returns the `LN` tag if the sequence is not stored but its length is known,
+    /// then the typed coverage tags in the order `RC`, `FC`, `KC`, followed by all other tags.
+    fn tags(&self) -> Vec<String> {
+        let length = match self.sequence {
+            Some(_) => None,
+            None => self.length.map(|length| format!("LN:i:{length}")),
+        };
+        let coverage = [
+            ("RC", self.read_count),
+            ("FC", self.fragment_count),
+            ("KC", self.kmer_count),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| format!("{name}:i:{value}")));
+
+        length
+            .into_iter()
+            .chain(coverage)
+            .chain(self.tags.iter().cloned())
+            .collect()
+    }
+}
+
+/// Node data that stores only the name and sequence length of a segment, as read by [`read_gfa1_topology_only`].
+///
+/// The sequence length is written as an `LN:i:` tag.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TopologyGfaNodeData {
+    name: GfaNodeName,
+    length: Option<usize>,
+}
+
+impl TopologyGfaNodeData {
+    pub fn new(name: impl ToString, length: Option<usize>) -> Self {
+        Self {
+            name: GfaNodeName::Text(name.to_string()),
+            length,
+        }
+    }
+
+    /// Returns the name as an integer if it is a decimal number without sign or leading zeros.
+    pub fn numeric_id(&self) -> Option<u64> {
+        self.name.numeric_id()
+    }
+}
+
+impl GfaNodeData for TopologyGfaNodeData {
+    fn name(&'_ self) -> Cow<'_, str> {
+        self.name.as_str()
+    }
+
+    fn sequence(&'_ self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn sequence_length(&self) -> Option<usize> {
+        self.length
+    }
+
+    fn tags(&self) -> Vec<String> {
+        self.length
+            .map(|length| format!("LN:i:{length}"))
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Node data that the GFA reader can build from an `S` line.
+trait FromGfaSegment: Sized {
+    /// Builds the node data from the name, the sequence unless it is `*`, and the tags of an `S` line.
+    fn from_gfa_segment(
+        name: GfaNodeName,
+        sequence: Option<&str>,
+        tags: &[&str],
+    ) -> Result<Self, GfaReadError>;
+}
+
+impl FromGfaSegment for PlainGfaNodeData {
+    fn from_gfa_segment(
+        name: GfaNodeName,
+        sequence: Option<&str>,
+        tags: &[&str],
+    ) -> Result<Self, GfaReadError> {
+        let mut node_data = PlainGfaNodeData {
+            name,
+            sequence: sequence.map(str::to_string),
+            ..PlainGfaNodeData::default()
+        };
+        for tag in tags.iter().filter(|tag| !tag.is_empty()) {
+            node_data.parse_tag(tag)?;
+        }
+        Ok(node_data)
+    }
+}
+
+impl FromGfaSegment for TopologyGfaNodeData {
+    fn from_gfa_segment(
+        name: GfaNodeName,
+        sequence: Option<&str>,
+        tags: &[&str],
+    ) -> Result<Self, GfaReadError> {
+        let length = match sequence {
+            Some(sequence) => Some(sequence.len()),
+            None => tags
+                .iter()
+                .find_map(|tag| tag.strip_prefix("LN:i:"))
+                .map(|length| {
+                    length
+                        .parse()
+                        .map_err(|_| GfaReadError::InvalidTagValue(format!("LN:i:{length}")))
+                })
+                .transpose()?,
+        };
+        Ok(Self { name, length })
     }
 }
 
 impl PlainGfaEdgeData {
     pub fn new(overlap: u16) -> Self {
-        Self { overlap }
+        Self {
+            overlap: Some(overlap),
+        }
+    }
+
+    /// Creates edge data for a link whose overlap is not specified, i.e. given as `*`.
+    ///
+    /// This is distinct from an overlap of length zero, even though [`GfaEdgeData::overlap`] returns zero for both.
+    pub fn new_unspecified() -> Self {
+        Self { overlap: None }
     }
 }
 
 impl GfaEdgeData for PlainGfaEdgeData {
     fn overlap(&self) -> u16 {
-        self.overlap
+        self.overlap.unwrap_or(0)
+    }
+
+    fn is_overlap_specified(&self) -> bool {
+        self.overlap.is_some()
     }
 }
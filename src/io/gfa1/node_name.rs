@@ -0,0 +1,134 @@
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::HashMap,
+    hash::{Hash, Hasher},
+};
+
+use crate::index::{GraphIndexInteger, NodeIndex};
+
+/// The name of a GFA segment, stored as an integer if it is a canonical decimal number.
+///
+/// Equality, hashing and ordering behave as if the name was always stored as a string.
+#[derive(Debug, Clone)]
+pub(super) enum GfaNodeName {
+    Numeric(u64),
+    Text(String),
+}
+
+impl GfaNodeName {
+    pub(super) fn as_str(&self) -> Cow<'_, str> {
+        match self {
+            Self::Numeric(id) => Cow::Owned(id.to_string()),
+            Self::Text(name) => Cow::Borrowed(name),
+        }
+    }
+
+    /// Returns the name as an integer if it is a canonical decimal number.
+    pub(super) fn numeric_id(&self) -> Option<u64> {
+        match self {
+            Self::Numeric(id) => Some(*id),
+            Self::Text(name) => parse_numeric_name(name),
+        }
+    }
+}
+
+impl Default for GfaNodeName {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl PartialEq for GfaNodeName {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Text(name), Self::Text(other_name)) => name == other_name,
+            _ => self.numeric_id().is_some() && self.numeric_id() == other.numeric_id(),
+        }
+    }
+}
+
+impl Eq for GfaNodeName {}
+
+impl Hash for GfaNodeName {
+    fn hash<State: Hasher>(&self, state: &mut State) {
+        match (self.numeric_id(), self) {
+            (Some(id), _) => id.hash(state),
+            (None, Self::Text(name)) => name.hash(state),
+            (None, Self::Numeric(_)) => unreachable!(),
+        }
+    }
+}
+
+impl PartialOrd for GfaNodeName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GfaNodeName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(&other.as_str())
+    }
+}
+
+/// Parses a segment name as an integer if it is written in canonical decimal form, i.e. without sign or leading zeros.
+///
+/// Other names such as `007` are not numeric, such that converting the integer back to a string yields the original name.
+pub(super) fn parse_numeric_name(name: &str) -> Option<u64> {
+    let is_canonical =
+        name.bytes().all(|byte| byte.is_ascii_digit()) && (name == "0" || !name.starts_with('0'));
+    if is_canonical {
+        name.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Maps segment names to nodes, keyed by integers as long as all names are numeric.
+pub(super) enum NodeNameMap<IndexType> {
+    Numeric(HashMap<u64, NodeIndex<IndexType>>),
+    Text(HashMap<String, NodeIndex<IndexType>>),
+}
+
+impl<IndexType: GraphIndexInteger> NodeNameMap<IndexType> {
+    /// Creates an empty map, which uses integer keys if `numeric` is true.
+    pub(super) fn new(numeric: bool) -> Self {
+        if numeric {
+            Self::Numeric(HashMap::new())
+        } else {
+            Self::Text(HashMap::new())
+        }
+    }
+
+    /// Inserts the given name and returns it in the representation used by the map.
+    ///
+    /// If the map uses integer keys and the name is not numeric, then the map falls back to string keys.
+    pub(super) fn insert(&mut self, name: &str, node: NodeIndex<IndexType>) -> GfaNodeName {
+        if let Self::Numeric(map) = self {
+            if let Some(id) = parse_numeric_name(name) {
+                map.insert(id, node);
+                return GfaNodeName::Numeric(id);
+            }
+
+            *self = Self::Text(
+                map.drain()
+                    .map(|(id, node)| (id.to_string(), node))
+                    .collect(),
+            );
+        }
+
+        let Self::Text(map) = self else {
+            unreachable!()
+        };
+        map.insert(name.to_string(), node);
+        GfaNodeName::Text(name.to_string())
+    }
+
+    pub(super) fn get(&self, name: &str) -> Option<NodeIndex<IndexType>> {
+        match self {
+            Self::Numeric(map) => map.get(&parse_numeric_name(name)?).copied(),
+            Self::Text(map) => map.get(name).copied(),
+        }
+    }
+}
@@ -1,55 +1,44 @@
+use std::borrow::Cow;
+
 use rand::{
-    SeedableRng,
+    Rng, SeedableRng,
     distr::{SampleString, slice::Choose},
     rngs::SmallRng,
 };
 
 use crate::{
     graph::{BidirectedAdjacencyArray, BidirectedEdge},
-    io::gfa1::{PlainGfaEdgeData, PlainGfaNodeData, read_gfa1, write_gfa1},
+    index::NodeIndex,
+    io::gfa1::{
+        DuplicateEdgePolicy, GFA_PROGRESS_INTERVAL_LINES, GfaEdgeData, GfaHeader, GfaJump,
+        GfaLineTypes, GfaNodeData, GfaReadError, GfaReadOptions, GfaStreamWriter, GfaWriteOptions,
+        PlainGfaEdgeData, PlainGfaNodeData, TopologyGfaNodeData, filter_gfa1_stream, read_gfa1,
+        read_gfa1_multi, read_gfa1_topology_only, read_gfa1_with_header, read_gfa1_with_jumps,
+        read_gfa1_with_options, read_gfa1_with_progress, write_gfa1, write_gfa1_filtered,
+        write_gfa1_lines, write_gfa1_with, write_gfa1_with_jumps, write_gfa1_with_options,
+    },
+    test_util::{directed, gfa_graph},
 };
 
-#[test]
-fn test_write_read_triangle() {
+fn triangle_graph() -> BidirectedAdjacencyArray<u16, PlainGfaNodeData, PlainGfaEdgeData> {
     let nodes = vec![
-        PlainGfaNodeData {
-            name: "N0".into(),
-            sequence: "000".into(),
-        },
-        PlainGfaNodeData {
-            name: "N1".into(),
-            sequence: "111".into(),
-        },
-        PlainGfaNodeData {
-            name: "N2".into(),
-            sequence: "222".into(),
-        },
-    ];
-    let edges = vec![
-        BidirectedEdge {
-            from: 0.into(),
-            from_forward: true,
-            to: 1.into(),
-            to_forward: true,
-            data: PlainGfaEdgeData { overlap: 0 },
-        },
-        BidirectedEdge {
-            from: 1.into(),
-            from_forward: true,
-            to: 2.into(),
-            to_forward: true,
-            data: PlainGfaEdgeData { overlap: 1 },
-        },
-        BidirectedEdge {
-            from: 2.into(),
-            from_forward: true,
-            to: 0.into(),
-            to_forward: true,
-            data: PlainGfaEdgeData { overlap: 2 },
-        },
+        PlainGfaNodeData::new("N0", "000"),
+        PlainGfaNodeData::new("N1", "111"),
+        PlainGfaNodeData::new("N2", "222"),
     ];
+    gfa_graph(
+        nodes,
+        &[
+            (directed(0, true), directed(1, true), 0),
+            (directed(1, true), directed(2, true), 1),
+            (directed(2, true), directed(0, true), 2),
+        ],
+    )
+}
 
-    let expected_graph = BidirectedAdjacencyArray::<u16, _, _>::new(nodes.into(), edges.into());
+#[test]
+fn test_write_read_triangle() {
+    let expected_graph = triangle_graph();
 
     let mut buffer = Vec::new();
     write_gfa1(&expected_graph, &mut buffer).unwrap();
@@ -72,11 +61,13 @@ fn test_write_read_large() {
         let expected_graph = BidirectedAdjacencyArray::<u16, _, _>::generate_random_graph(
             10,
             100,
-            |node_index, rng| PlainGfaNodeData {
-                name: format!("node{node_index}"),
-                sequence: dna_characters.sample_string(rng, 10),
+            |node_index, rng| {
+                PlainGfaNodeData::new(
+                    format!("node{node_index}"),
+                    dna_characters.sample_string(rng, 10),
+                )
             },
-            |_| PlainGfaEdgeData { overlap: 0 },
+            |_| PlainGfaEdgeData::new(0),
             &mut rng,
         )
         .unwrap();
@@ -88,3 +79,644 @@ fn test_write_read_large() {
         expected_graph.expect_equal(&actual_graph);
     }
 }
+
+#[test]
+fn test_write_read_large_with_overlaps() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let dna_characters = Choose::new(&['A', 'C', 'G', 'T']).unwrap();
+    let sequence_length = |node: NodeIndex<u16>| node.into_usize() + 1;
+
+    for _ in 0..100 {
+        let expected_graph =
+            BidirectedAdjacencyArray::<u16, _, _>::generate_random_graph_with_endpoint_edge_data(
+                10,
+                50,
+                |node_index, rng| {
+                    PlainGfaNodeData::new(
+                        format!("node{node_index}"),
+                        dna_characters.sample_string(rng, sequence_length(node_index)),
+                    )
+                },
+                |from, _, to, _, rng| {
+                    let max_overlap = sequence_length(from).min(sequence_length(to));
+                    PlainGfaEdgeData::new(rng.random_range(0..=max_overlap).try_into().unwrap())
+                },
+                &mut rng,
+            )
+            .unwrap();
+        assert_eq!(expected_graph.validate_overlaps(), Ok(()));
+
+        let mut buffer = Vec::new();
+        write_gfa1(&expected_graph, &mut buffer).unwrap();
+        let actual_graph = read_gfa1::<u16>(&mut buffer.as_slice()).unwrap();
+
+        expected_graph.expect_equal(&actual_graph);
+    }
+}
+
+#[test]
+fn test_filter_stream_drops_links_of_removed_segment() {
+    let gfa = "H\tVN:Z:1.0\nS\tN0\t000\nS\tN1\t111\nS\tN2\t222\nL\tN0\t+\tN1\t+\t0M\nL\tN1\t+\tN2\t-\t1M\nL\tN2\t+\tN0\t+\t2M\n";
+
+    let mut buffer = Vec::new();
+    filter_gfa1_stream(
+        &mut gfa.as_bytes(),
+        &mut buffer,
+        |name, _| name != "N1",
+        |_, _, _, _| true,
+    )
+    .unwrap();
+    let actual_gfa = std::str::from_utf8(&buffer).unwrap().trim();
+
+    let expected_gfa = "H\tVN:Z:1.0\nS\tN0\t000\nS\tN2\t222\nL\tN2\t+\tN0\t+\t2M";
+    assert_eq!(expected_gfa, actual_gfa);
+}
+
+#[test]
+fn test_read_crlf_and_blank_lines() {
+    let lf_gfa = "H\tVN:Z:1.0\nS\tN0\t000\nS\tN1\t111\nL\tN0\t+\tN1\t-\t3M\n";
+    let crlf_gfa =
+        "H\tVN:Z:1.0\r\n\r\nS\tN0\t000\t\r\nS\tN1\t111\r\n\nL\tN0\t+\tN1\t-\t3M\t\t\r\n\r\n";
+
+    let expected_graph = read_gfa1::<u16>(&mut lf_gfa.as_bytes()).unwrap();
+    let actual_graph = read_gfa1::<u16>(&mut crlf_gfa.as_bytes()).unwrap();
+
+    expected_graph.expect_equal(&actual_graph);
+}
+
+#[test]
+fn test_write_read_with_options() {
+    let expected_graph = triangle_graph();
+
+    let options = GfaWriteOptions {
+        emit_header: false,
+        ..Default::default()
+    };
+    let mut buffer = Vec::new();
+    write_gfa1_with_options(&expected_graph, &mut buffer, &options).unwrap();
+    let actual_gfa = std::str::from_utf8(&buffer).unwrap().trim();
+    let expected_gfa = "S\tN0\t000\nS\tN1\t111\nS\tN2\t222\nL\tN0\t+\tN1\t+\t0M\nL\tN1\t+\tN2\t+\t1M\nL\tN2\t+\tN0\t+\t2M";
+    assert_eq!(expected_gfa, actual_gfa);
+    let actual_graph = read_gfa1::<u16>(&mut buffer.as_slice()).unwrap();
+    expected_graph.expect_equal(&actual_graph);
+
+    let options = GfaWriteOptions {
+        separator: ' ',
+        ..Default::default()
+    };
+    let mut buffer = Vec::new();
+    write_gfa1_with_options(&expected_graph, &mut buffer, &options).unwrap();
+    let actual_gfa = std::str::from_utf8(&buffer).unwrap().trim();
+    let expected_gfa =
+        "H VN:Z:1.0\nS N0 000\nS N1 111\nS N2 222\nL N0 + N1 + 0M\nL N1 + N2 + 1M\nL N2 + N0 + 2M";
+    assert_eq!(expected_gfa, actual_gfa);
+    let read_options = GfaReadOptions {
+        separator: ' ',
+        ..Default::default()
+    };
+    let actual_graph =
+        read_gfa1_with_options::<u16>(&mut buffer.as_slice(), &read_options).unwrap();
+    expected_graph.expect_equal(&actual_graph);
+
+    // Space-separated lines are only split when reading leniently.
+    assert_eq!(
+        read_gfa1::<u16>(&mut buffer.as_slice())
+            .unwrap()
+            .node_count(),
+        0
+    );
+    let read_options = GfaReadOptions {
+        lenient_separator: true,
+        ..Default::default()
+    };
+    let actual_graph =
+        read_gfa1_with_options::<u16>(&mut buffer.as_slice(), &read_options).unwrap();
+    expected_graph.expect_equal(&actual_graph);
+
+    let options = GfaWriteOptions {
+        separator: ';',
+        ..Default::default()
+    };
+    let mut buffer = Vec::new();
+    write_gfa1_with_options(&expected_graph, &mut buffer, &options).unwrap();
+    assert!(
+        std::str::from_utf8(&buffer)
+            .unwrap()
+            .starts_with("H;VN:Z:1.0\nS;N0;000\n")
+    );
+    let read_options = GfaReadOptions {
+        separator: ';',
+        ..Default::default()
+    };
+    let actual_graph =
+        read_gfa1_with_options::<u16>(&mut buffer.as_slice(), &read_options).unwrap();
+    expected_graph.expect_equal(&actual_graph);
+}
+
+#[test]
+fn test_write_read_missing_sequence() {
+    let gfa = "H\tVN:Z:1.0\nS\tN0\t*\nS\tN1\tACGT\nL\tN0\t+\tN1\t-\t0M";
+
+    let graph = read_gfa1::<u16>(&mut gfa.as_bytes()).unwrap();
+    assert_eq!(
+        graph.node_data(0.into()),
+        &PlainGfaNodeData::new_without_sequence("N0")
+    );
+    assert_eq!(
+        graph.node_data(1.into()),
+        &PlainGfaNodeData::new("N1", "ACGT")
+    );
+
+    let mut buffer = Vec::new();
+    write_gfa1(&graph, &mut buffer).unwrap();
+    assert_eq!(std::str::from_utf8(&buffer).unwrap().trim(), gfa);
+}
+
+#[test]
+fn test_stream_writer_matches_write_gfa1() {
+    let graph = triangle_graph();
+    let mut expected = Vec::new();
+    write_gfa1(&graph, &mut expected).unwrap();
+
+    let mut actual = Vec::new();
+    let mut writer = GfaStreamWriter::new(&mut actual);
+    writer.write_header(&GfaHeader::default()).unwrap();
+    writer.write_segment("N0", Some("000"), &[]).unwrap();
+    writer.write_segment("N1", Some("111"), &[]).unwrap();
+    writer.write_segment("N2", Some("222"), &[]).unwrap();
+    writer
+        .write_link("N0", true, "N1", true, Some(0), &[])
+        .unwrap();
+    writer
+        .write_link("N1", true, "N2", true, Some(1), &[])
+        .unwrap();
+    writer
+        .write_link("N2", true, "N0", true, Some(2), &[])
+        .unwrap();
+    writer.finish().unwrap();
+
+    assert_eq!(
+        std::str::from_utf8(&expected).unwrap(),
+        std::str::from_utf8(&actual).unwrap()
+    );
+}
+
+#[test]
+fn test_write_read_header_tags() {
+    let gfa = "H\tVN:Z:1.1\tPN:Z:vg\tXX:i:7\nS\tN0\tACGT\n";
+
+    let (graph, header) = read_gfa1_with_header::<u16>(&mut gfa.as_bytes()).unwrap();
+    assert_eq!(
+        header,
+        GfaHeader {
+            version: Some("1.1".to_string()),
+            tags: vec!["PN:Z:vg".to_string(), "XX:i:7".to_string()],
+        }
+    );
+
+    let mut buffer = Vec::new();
+    let options = GfaWriteOptions {
+        header,
+        ..Default::default()
+    };
+    write_gfa1_with_options(&graph, &mut buffer, &options).unwrap();
+    assert_eq!(std::str::from_utf8(&buffer).unwrap(), gfa);
+
+    let (_, header) = read_gfa1_with_header::<u16>(&mut "S\tN0\tACGT\n".as_bytes()).unwrap();
+    assert_eq!(header, GfaHeader::default());
+}
+
+#[test]
+fn test_write_read_coverage_tags() {
+    let gfa = "H\tVN:Z:1.0\nS\tN0\tACGT\tRC:i:12\tKC:i:40\tXX:Z:foo\nS\tN1\t*\tFC:i:3\n";
+
+    let graph = read_gfa1::<u16>(&mut gfa.as_bytes()).unwrap();
+    let node = graph.node_data(0.into());
+    assert_eq!(node.read_count(), Some(12));
+    assert_eq!(node.fragment_count(), None);
+    assert_eq!(node.kmer_count(), Some(40));
+    assert_eq!(node.other_tags(), ["XX:Z:foo".to_string()]);
+    let node = graph.node_data(1.into());
+    assert_eq!(node.read_count(), None);
+    assert_eq!(node.fragment_count(), Some(3));
+    assert_eq!(node.kmer_count(), None);
+    assert!(node.other_tags().is_empty());
+
+    let mut buffer = Vec::new();
+    write_gfa1(&graph, &mut buffer).unwrap();
+    assert_eq!(std::str::from_utf8(&buffer).unwrap(), gfa);
+
+    assert!(matches!(
+        read_gfa1::<u16>(&mut "S\tN0\tACGT\tKC:i:many\n".as_bytes()),
+        Err(GfaReadError::InvalidTagValue(tag)) if tag == "KC:i:many"
+    ));
+}
+
+#[test]
+fn test_write_read_jumps() {
+    let gfa = "H\tVN:Z:1.0\nS\tN0\tACGT\nS\tN1\tTT\nL\tN0\t+\tN1\t+\t0M\nJ\tN0\t-\tN1\t+\t120\tSC:i:1\nJ\tN1\t+\tN1\t-\t*\n";
+
+    let (graph, header, jumps) = read_gfa1_with_jumps::<u16>(&mut gfa.as_bytes()).unwrap();
+    assert_eq!(graph.edge_count(), 1);
+    assert_eq!(
+        jumps,
+        vec![
+            GfaJump {
+                from: 0.into(),
+                from_forward: false,
+                to: 1.into(),
+                to_forward: true,
+                distance: Some(120),
+                tags: vec!["SC:i:1".to_string()],
+            },
+            GfaJump {
+                from: 1.into(),
+                from_forward: true,
+                to: 1.into(),
+                to_forward: false,
+                distance: None,
+                tags: Vec::new(),
+            },
+        ]
+    );
+
+    let mut buffer = Vec::new();
+    let options = GfaWriteOptions {
+        header,
+        ..Default::default()
+    };
+    write_gfa1_with_jumps(&graph, &jumps, &mut buffer, &options).unwrap();
+    assert_eq!(std::str::from_utf8(&buffer).unwrap(), gfa);
+
+    assert!(matches!(
+        read_gfa1_with_jumps::<u16>(&mut "S\tN0\tA\nJ\tN0\t+\tN0\t+\tfar\n".as_bytes()),
+        Err(GfaReadError::InvalidJumpDistance(distance)) if distance == "far"
+    ));
+    assert!(matches!(
+        read_gfa1_with_jumps::<u16>(&mut "S\tN0\tA\nJ\tN0\t+\tN9\t+\t*\n".as_bytes()),
+        Err(GfaReadError::UnknownNodeName(name)) if name == "N9"
+    ));
+}
+
+#[test]
+fn test_read_skips_bad_jumps() {
+    let gfa = "S\tN0\tA\nS\tN1\tC\nJ\tN0\t+\tN0\t+\tfar\nJ\tN0\t+\tN9\t+\t*\nJ\tN0\nL\tN0\t+\tN1\t+\t0M\n";
+
+    let graph = read_gfa1::<u16>(&mut gfa.as_bytes()).unwrap();
+    assert_eq!(graph.node_count(), 2);
+    assert_eq!(graph.edge_count(), 1);
+
+    let (graph, _) = read_gfa1_with_header::<u16>(&mut gfa.as_bytes()).unwrap();
+    assert_eq!(graph.edge_count(), 1);
+}
+
+#[test]
+fn test_write_filtered() {
+    let graph = triangle_graph();
+
+    let mut buffer = Vec::new();
+    write_gfa1_filtered(
+        &graph,
+        &mut buffer,
+        |edge_data| edge_data.overlap() != 2,
+        |node_data| node_data.name() != "N1",
+    )
+    .unwrap();
+    let actual_gfa = std::str::from_utf8(&buffer).unwrap().trim();
+    // The edge N2 -> N0 is filtered by the edge predicate, and the others by their endpoint N1.
+    assert_eq!(actual_gfa, "H\tVN:Z:1.0\nS\tN0\t000\nS\tN2\t222");
+
+    let mut buffer = Vec::new();
+    write_gfa1_filtered(&graph, &mut buffer, |_| true, |_| true).unwrap();
+    let mut expected = Vec::new();
+    write_gfa1(&graph, &mut expected).unwrap();
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+fn test_write_gfa1_with() {
+    let graph = triangle_graph();
+    let numbered_graph = graph
+        .clone()
+        .map_node_data(|node_data| {
+            let id = node_data.name()[1..].parse::<u32>().unwrap();
+            (id, node_data.sequence().map(Cow::into_owned))
+        })
+        .map_edge_data(|edge_data| edge_data.overlap());
+
+    let mut buffer = Vec::new();
+    write_gfa1_with(
+        &numbered_graph,
+        &mut buffer,
+        |(id, _)| Cow::Owned(format!("N{id}")),
+        |(_, sequence)| sequence.as_deref().map(Cow::Borrowed),
+        |overlap| *overlap,
+    )
+    .unwrap();
+    let mut expected = Vec::new();
+    write_gfa1(&graph, &mut expected).unwrap();
+    assert_eq!(buffer, expected);
+
+    let mut buffer = Vec::new();
+    write_gfa1_with(
+        &numbered_graph,
+        &mut buffer,
+        |(id, _)| Cow::Owned(id.to_string()),
+        |_| None,
+        |_| 0,
+    )
+    .unwrap();
+    let actual_gfa = std::str::from_utf8(&buffer).unwrap().trim();
+    assert!(actual_gfa.starts_with("H\tVN:Z:1.0\nS\t0\t*\nS\t1\t*\nS\t2\t*\nL\t0\t+\t1\t+\t0M\n"));
+}
+
+#[test]
+fn test_read_with_progress() {
+    let line_count = 2 * GFA_PROGRESS_INTERVAL_LINES + 1;
+    let mut gfa = "H\tVN:Z:1.0\n".to_string();
+    for node in 1..line_count {
+        gfa.push_str(&format!("S\tN{node}\tA\n"));
+    }
+
+    let mut progress = Vec::new();
+    let graph =
+        read_gfa1_with_progress::<u32>(&mut gfa.as_bytes(), |bytes| progress.push(bytes)).unwrap();
+    assert_eq!(graph.node_count() as u64, line_count - 1);
+
+    let bytes_after_lines = |lines: u64| {
+        gfa.split_inclusive('\n')
+            .take(lines as usize)
+            .map(|line| line.len() as u64)
+            .sum::<u64>()
+    };
+    assert_eq!(
+        progress,
+        vec![
+            bytes_after_lines(GFA_PROGRESS_INTERVAL_LINES),
+            bytes_after_lines(2 * GFA_PROGRESS_INTERVAL_LINES),
+            gfa.len() as u64,
+        ]
+    );
+}
+
+#[test]
+fn test_read_duplicate_edge_policy() {
+    let gfa =
+        "S\tN0\tAAA\nS\tN1\tCCC\nL\tN0\t+\tN1\t-\t1M\nL\tN1\t+\tN0\t-\t2M\nL\tN0\t+\tN1\t+\t0M\n";
+    let read = |duplicate_edge_policy| {
+        read_gfa1_with_options::<u16>(
+            &mut gfa.as_bytes(),
+            &GfaReadOptions {
+                duplicate_edge_policy,
+                ..GfaReadOptions::default()
+            },
+        )
+    };
+
+    let graph = read(DuplicateEdgePolicy::Keep).unwrap();
+    assert_eq!(graph.edge_count(), 3);
+    graph.expect_equal(&read_gfa1(&mut gfa.as_bytes()).unwrap());
+
+    assert!(matches!(
+        read(DuplicateEdgePolicy::Error),
+        Err(GfaReadError::DuplicateLink(from, to)) if from == "N1" && to == "N0"
+    ));
+
+    let graph = read(DuplicateEdgePolicy::Merge(|existing, duplicate| {
+        *existing = PlainGfaEdgeData::new(existing.overlap().max(duplicate.overlap()))
+    }))
+    .unwrap();
+    assert_eq!(graph.edge_count(), 2);
+    assert_eq!(graph.edge(0.into()).data().overlap(), 2);
+    assert_eq!(graph.edge(1.into()).data().overlap(), 0);
+}
+
+#[test]
+fn test_read_collapse_reverse_complement_links() {
+    let gfa = "S\tN0\tAAA\nS\tN1\tCCC\nL\tN0\t+\tN1\t+\t1M\nL\tN1\t-\tN0\t-\t2M\nL\tN0\t+\tN0\t-\t0M\nL\tN0\t+\tN0\t-\t0M\nL\tN1\t+\tN0\t+\t0M\n";
+    let read = |duplicate_edge_policy| {
+        read_gfa1_with_options::<u16>(
+            &mut gfa.as_bytes(),
+            &GfaReadOptions {
+                duplicate_edge_policy,
+                collapse_reverse_complement_links: true,
+                ..GfaReadOptions::default()
+            },
+        )
+    };
+
+    // The reverse complement of the first link is skipped, while the repeated palindromic self loop is a plain duplicate.
+    let graph = read(DuplicateEdgePolicy::Keep).unwrap();
+    let expected_edges = vec![
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, true), 1),
+        BidirectedEdge::new_gfa(directed(0, true), directed(0, false), 0),
+        BidirectedEdge::new_gfa(directed(0, true), directed(0, false), 0),
+        BidirectedEdge::new_gfa(directed(1, true), directed(0, true), 0),
+    ];
+    let expected_graph = BidirectedAdjacencyArray::new(
+        vec![
+            PlainGfaNodeData::new("N0", "AAA"),
+            PlainGfaNodeData::new("N1", "CCC"),
+        ]
+        .into(),
+        expected_edges.into(),
+    );
+    expected_graph.expect_equal(&graph);
+    assert_eq!(
+        read_gfa1::<u16>(&mut gfa.as_bytes()).unwrap().edge_count(),
+        5
+    );
+
+    assert!(matches!(
+        read(DuplicateEdgePolicy::Error),
+        Err(GfaReadError::DuplicateLink(from, to)) if from == "N0" && to == "N0"
+    ));
+}
+
+#[test]
+fn test_read_numeric_node_names() {
+    let read_numeric = |gfa: &str| {
+        read_gfa1_with_options::<u16>(
+            &mut gfa.as_bytes(),
+            &GfaReadOptions {
+                numeric_node_names: true,
+                ..GfaReadOptions::default()
+            },
+        )
+    };
+
+    let numeric_gfa =
+        "H\tVN:Z:1.0\nS\t12\tAAA\nS\t0\tCCC\nL\t12\t+\t0\t-\t1M\nL\t0\t+\t12\t+\t0M\n";
+    let mixed_gfa = "H\tVN:Z:1.0\nS\t12\tAAA\nS\t007\tCCC\nS\tN2\t*\nL\t12\t+\t007\t-\t1M\nL\tN2\t+\t12\t+\t0M\n";
+    for gfa in [numeric_gfa, mixed_gfa] {
+        let graph = read_numeric(gfa).unwrap();
+        graph.expect_equal(&read_gfa1(&mut gfa.as_bytes()).unwrap());
+
+        let mut buffer = Vec::new();
+        write_gfa1(&graph, &mut buffer).unwrap();
+        assert_eq!(std::str::from_utf8(&buffer).unwrap(), gfa);
+    }
+
+    let graph = read_numeric(numeric_gfa).unwrap();
+    assert_eq!(graph.node_data(0.into()).numeric_id(), Some(12));
+    assert_eq!(graph.node_data(1.into()).name(), "0");
+    assert_eq!(graph.node_data(1.into()).numeric_id(), Some(0));
+
+    let graph = read_numeric(mixed_gfa).unwrap();
+    assert_eq!(graph.node_data(0.into()).numeric_id(), Some(12));
+    assert_eq!(graph.node_data(1.into()).numeric_id(), None);
+    assert_eq!(graph.node_data(2.into()).numeric_id(), None);
+    assert_eq!(
+        graph.node_data(0.into()),
+        &PlainGfaNodeData::new("12", "AAA")
+    );
+
+    assert!(matches!(
+        read_numeric("S\t1\tA\nL\t01\t+\t1\t+\t0M\n"),
+        Err(GfaReadError::UnknownNodeName(name)) if name == "01"
+    ));
+}
+
+#[test]
+fn test_read_multi() {
+    let first_gfa = "H\tVN:Z:1.0\nS\tN0\tAAA\nS\tN1\tCCC\nL\tN0\t+\tN1\t+\t0M\n";
+    // Reuses the node names of the first graph for different nodes.
+    let second_gfa = "H\tVN:Z:1.0\nS\tN1\tGG\nS\tN0\tTT\nL\tN1\t+\tN0\t-\t1M\n";
+    let stream = format!("{first_gfa}\n{second_gfa}{first_gfa}");
+
+    let graphs = read_gfa1_multi::<u16>(&mut stream.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(graphs.len(), 3);
+    for (graph, gfa) in graphs.iter().zip([first_gfa, second_gfa, first_gfa]) {
+        graph.expect_equal(&read_gfa1(&mut gfa.as_bytes()).unwrap());
+    }
+
+    // Lines before the first header form their own graph.
+    let stream = format!("S\tX\tA\n{first_gfa}");
+    assert_eq!(read_gfa1_multi::<u16>(&mut stream.as_bytes()).count(), 2);
+    assert_eq!(read_gfa1_multi::<u16>(&mut "\n\n".as_bytes()).count(), 0);
+
+    // Names of previous graphs are not known in later graphs.
+    let stream = format!("{first_gfa}H\tVN:Z:1.0\nL\tN0\t+\tN1\t+\t0M\n{first_gfa}");
+    let results = read_gfa1_multi::<u16>(&mut stream.as_bytes()).collect::<Vec<_>>();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(matches!(results[1], Err(GfaReadError::UnknownNodeName(_))));
+}
+
+#[test]
+fn test_read_gfa1_topology_only() {
+    let gfa = "H\tVN:Z:1.0\nS\tN0\tACGT\tRC:i:4\nS\tN1\t*\tLN:i:7\nS\tN2\t*\nL\tN0\t+\tN1\t-\t2M\nL\tN1\t+\tN2\t+\t0M\n";
+    let graph = read_gfa1_topology_only::<u16>(&mut gfa.as_bytes()).unwrap();
+    let plain_graph = read_gfa1::<u16>(&mut gfa.as_bytes()).unwrap();
+
+    assert_eq!(
+        graph
+            .iter_node_data()
+            .map(|(_, node_data)| node_data.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            TopologyGfaNodeData::new("N0", Some(4)),
+            TopologyGfaNodeData::new("N1", Some(7)),
+            TopologyGfaNodeData::new("N2", None),
+        ]
+    );
+    assert_eq!(graph.node_data(0.into()).sequence(), None);
+    assert_eq!(graph.edge_count(), plain_graph.edge_count());
+    for edge in graph.iter_edges() {
+        assert_eq!(graph.edge(edge).from(), plain_graph.edge(edge).from());
+        assert_eq!(graph.edge(edge).to(), plain_graph.edge(edge).to());
+        assert_eq!(graph.edge(edge).data(), plain_graph.edge(edge).data());
+    }
+    assert_eq!(graph.total_sequence_length(), 11);
+    assert_eq!(graph.degree(1.into()), 2);
+
+    let mut buffer = Vec::new();
+    write_gfa1(&graph, &mut buffer).unwrap();
+    assert_eq!(
+        std::str::from_utf8(&buffer).unwrap(),
+        "H\tVN:Z:1.0\nS\tN0\t*\tLN:i:4\nS\tN1\t*\tLN:i:7\nS\tN2\t*\nL\tN0\t+\tN1\t-\t2M\nL\tN1\t+\tN2\t+\t0M\n"
+    );
+
+    assert!(matches!(
+        read_gfa1_topology_only::<u16>(&mut "S\tN0\t*\tLN:i:x\n".as_bytes()),
+        Err(GfaReadError::InvalidTagValue(tag)) if tag == "LN:i:x"
+    ));
+}
+
+#[test]
+fn test_segment_length_round_trip() {
+    let gfa = "H\tVN:Z:1.0\nS\tx\t*\tLN:i:100\tRC:i:3\nS\ty\tACG\tLN:i:3\n";
+    let graph = read_gfa1::<u16>(&mut gfa.as_bytes()).unwrap();
+
+    assert_eq!(graph.node_data(0.into()).sequence_length(), Some(100));
+    assert_eq!(graph.node_data(0.into()), &{
+        let mut node_data = PlainGfaNodeData::new_with_length("x", 100);
+        node_data.set_read_count(Some(3));
+        node_data
+    });
+    assert_eq!(graph.node_data(1.into()).sequence_length(), Some(3));
+    assert_eq!(graph.node_data(1.into()).other_tags(), ["LN:i:3"]);
+
+    let mut buffer = Vec::new();
+    write_gfa1(&graph, &mut buffer).unwrap();
+    assert_eq!(std::str::from_utf8(&buffer).unwrap(), gfa);
+
+    assert!(matches!(
+        read_gfa1::<u16>(&mut "S\tx\t*\tLN:i:x\n".as_bytes()),
+        Err(GfaReadError::InvalidTagValue(tag)) if tag == "LN:i:x"
+    ));
+}
+
+#[test]
+fn test_unspecified_overlap() {
+    let gfa = "H\tVN:Z:1.0\nS\tN0\tACGT\nS\tN1\tAC\nL\tN0\t+\tN1\t-\t*\nL\tN1\t+\tN0\t+\t0M\n";
+    let graph = read_gfa1::<u16>(&mut gfa.as_bytes()).unwrap();
+
+    let unspecified = graph.edge(0.into()).data();
+    let zero = graph.edge(1.into()).data();
+    assert_eq!(unspecified, &PlainGfaEdgeData::new_unspecified());
+    assert!(!unspecified.is_overlap_specified());
+    assert_eq!(unspecified.overlap(), 0);
+    assert_eq!(zero, &PlainGfaEdgeData::new(0));
+    assert!(zero.is_overlap_specified());
+    assert_ne!(unspecified, zero);
+
+    let mut buffer = Vec::new();
+    write_gfa1(&graph, &mut buffer).unwrap();
+    assert_eq!(std::str::from_utf8(&buffer).unwrap(), gfa);
+}
+
+#[test]
+fn test_write_gfa1_lines() {
+    let gfa =
+        "H\tVN:Z:1.0\nS\tN0\tACGT\tRC:i:4\nS\tN1\t*\nL\tN0\t+\tN1\t-\t2M\nL\tN1\t+\tN0\t+\t*\n";
+    let graph = read_gfa1::<u16>(&mut gfa.as_bytes()).unwrap();
+    let write_lines = |line_types| {
+        let mut buffer = Vec::new();
+        write_gfa1_lines(&graph, &mut buffer, line_types).unwrap();
+        String::from_utf8(buffer).unwrap()
+    };
+
+    assert_eq!(write_lines(GfaLineTypes::ALL), gfa);
+    assert_eq!(write_lines(GfaLineTypes::NONE), "");
+    assert_eq!(write_lines(GfaLineTypes::HEADER), "H\tVN:Z:1.0\n");
+    assert_eq!(
+        write_lines(GfaLineTypes::SEGMENTS),
+        "S\tN0\tACGT\tRC:i:4\nS\tN1\t*\n"
+    );
+    assert_eq!(
+        write_lines(GfaLineTypes::LINKS),
+        "L\tN0\t+\tN1\t-\t2M\nL\tN1\t+\tN0\t+\t*\n"
+    );
+
+    let mut line_types = GfaLineTypes::HEADER;
+    line_types |= GfaLineTypes::LINKS;
+    assert!(line_types.contains(GfaLineTypes::LINKS));
+    assert!(!line_types.contains(GfaLineTypes::HEADER | GfaLineTypes::SEGMENTS));
+    assert_eq!(
+        write_lines(line_types),
+        "H\tVN:Z:1.0\nL\tN0\t+\tN1\t-\t2M\nL\tN1\t+\tN0\t+\t*\n"
+    );
+}
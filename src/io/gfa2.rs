@@ -0,0 +1,342 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    io::{BufRead, BufReader, BufWriter, Read, Write},
+};
+
+use log::warn;
+use tagged_vec::TaggedVec;
+use thiserror::Error;
+
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge},
+    index::{EdgeIndex, GraphIndexInteger, NodeIndex},
+    io::gfa1::{GfaEdgeData, GfaNodeData, PlainGfaEdgeData, PlainGfaNodeData},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Error, Debug)]
+pub enum Gfa2ReadError {
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("a header line was found after other lines")]
+    WronglyPositionedHeader,
+
+    #[error("an S line is missing the sequence name")]
+    MissingSequenceNameInSLine,
+
+    #[error("an S line is missing the segment length")]
+    MissingSegmentLengthInSLine,
+
+    #[error("invalid segment length '{0}' in an S line")]
+    InvalidSegmentLength(String),
+
+    #[error("an E line is missing the seven fields specifying the edge endpoints and positions")]
+    ELineTooShort,
+
+    #[error("unknown node name '{0}' in an E line")]
+    UnknownNodeName(String),
+
+    #[error("segment reference '{0}' in an E line has no orientation sign")]
+    MissingOrientationInSegmentReference(String),
+
+    #[error("invalid position '{0}' in an E line")]
+    InvalidPosition(String),
+
+    #[error("overlap of length {0} does not fit into the edge data")]
+    OverlapTooLong(usize),
+}
+
+/// Reads a graph from GFA2.
+///
+/// Segments become nodes and dovetail `E` lines become edges, such that the resulting graph
+/// is the same as when reading the equivalent GFA1 with [`read_gfa1`](crate::io::gfa1::read_gfa1).
+/// The overlap of an edge is the length of its alignment on the first segment.
+///
+/// `E` lines that do not describe a dovetail overlap, e.g. containments, cannot be represented
+/// in a bidirected graph and are skipped with a warning, as are all other unsupported line types.
+pub fn read_gfa2<IndexType: GraphIndexInteger>(
+    reader: &mut impl Read,
+) -> Result<BidirectedAdjacencyArray<IndexType, PlainGfaNodeData, PlainGfaEdgeData>, Gfa2ReadError>
+{
+    let reader = BufReader::new(reader);
+    let mut node_name_to_node = HashMap::new();
+    let mut nodes = TaggedVec::<NodeIndex<IndexType>, _>::new();
+    let mut edges = TaggedVec::<EdgeIndex<IndexType>, _>::new();
+    let mut is_header_allowed = true;
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line = line.split('\t').map(str::trim).collect::<Vec<_>>();
+
+        match line[0] {
+            "H" => {
+                if is_header_allowed {
+                    if line.get(1) != Some(&"VN:Z:2.0") {
+                        warn!("Unsupported GFA version");
+                    }
+                } else {
+                    return Err(Gfa2ReadError::WronglyPositionedHeader);
+                }
+            }
+
+            "S" => {
+                let name = line
+                    .get(1)
+                    .ok_or(Gfa2ReadError::MissingSequenceNameInSLine)?
+                    .to_string();
+                let length_str = line
+                    .get(2)
+                    .ok_or(Gfa2ReadError::MissingSegmentLengthInSLine)?;
+                let length = length_str
+                    .parse::<usize>()
+                    .map_err(|_| Gfa2ReadError::InvalidSegmentLength(length_str.to_string()))?;
+                let node_data = match line.get(3) {
+                    None | Some(&"*") => PlainGfaNodeData::new_with_length(&name, length),
+                    Some(sequence) => PlainGfaNodeData::new(&name, sequence),
+                };
+                let node = nodes.push(node_data);
+                node_name_to_node.insert(name, (node, length));
+            }
+
+            "E" => {
+                if line.len() < 8 {
+                    return Err(Gfa2ReadError::ELineTooShort);
+                }
+
+                let from = parse_segment_end(&node_name_to_node, line[2], line[4], line[5])?;
+                let to = parse_segment_end(&node_name_to_node, line[3], line[6], line[7])?;
+                let overlap = from.alignment_length;
+                let overlap =
+                    u16::try_from(overlap).map_err(|_| Gfa2ReadError::OverlapTooLong(overlap))?;
+
+                // The edge leaves the segment whose oriented sequence ends with the alignment,
+                // and enters the segment whose oriented sequence starts with it.
+                let (from, to) = match (from.side, to.side) {
+                    (
+                        AlignedSide::End | AlignedSide::Whole,
+                        AlignedSide::Start | AlignedSide::Whole,
+                    ) => (from, to),
+                    (AlignedSide::Start | AlignedSide::Whole, AlignedSide::End) => (to, from),
+                    _ => {
+                        warn!(
+                            "Skipping E line that is not a dovetail overlap: {}",
+                            line.join("\t")
+                        );
+                        continue;
+                    }
+                };
+
+                edges.push(BidirectedEdge {
+                    from: from.node,
+                    from_forward: from.forward,
+                    to: to.node,
+                    to_forward: to.forward,
+                    data: if overlap == 0 && line.get(8) == Some(&"*") {
+                        PlainGfaEdgeData::new_unspecified()
+                    } else {
+                        PlainGfaEdgeData::new(overlap)
+                    },
+                });
+            }
+
+            other => {
+                warn!("Unsupported GFA line type: {}", other);
+            }
+        }
+
+        is_header_allowed = false;
+    }
+
+    Ok(BidirectedAdjacencyArray::new(nodes, edges))
+}
+
+/// Writes a graph as GFA2.
+///
+/// Each edge becomes a dovetail `E` line whose alignment covers `overlap` characters at the
+/// respective ends of its segments. The alignment field holds the CIGAR string of the edge data,
+/// or `{overlap}M` if it has none, or `*` if the overlap is unspecified.
+///
+/// Segments whose length is unknown are written with length zero.
+/// If the overlap of an edge exceeds the length of one of its segments, an error of kind
+/// [`InvalidInput`](std::io::ErrorKind::InvalidInput) is returned, since its positions could not be read back.
+pub fn write_gfa2<IndexType: GraphIndexInteger, NodeData: GfaNodeData, EdgeData: GfaEdgeData>(
+    graph: &BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+    writer: &mut impl Write,
+) -> Result<(), std::io::Error> {
+    let mut writer = BufWriter::new(writer);
+
+    // Write header.
+    writeln!(writer, "H\tVN:Z:2.0")?;
+
+    // Write nodes.
+    for node in graph.iter_nodes() {
+        let node_data = graph.node_data(node);
+        writeln!(
+            writer,
+            "S\t{}\t{}\t{}",
+            node_data.name(),
+            node_data.sequence_length().unwrap_or(0),
+            node_data.sequence().unwrap_or(Cow::Borrowed("*")),
+        )?;
+    }
+
+    // Write edges.
+    for edge in graph.iter_edges() {
+        let edge_data = graph.edge(edge);
+        let overlap = usize::from(edge_data.data().overlap());
+
+        let from_node_data = graph.node_data(edge_data.from().into_bidirected());
+        let to_node_data = graph.node_data(edge_data.to().into_bidirected());
+        let from_length = from_node_data.sequence_length().unwrap_or(0);
+        let to_length = to_node_data.sequence_length().unwrap_or(0);
+        for (node_data, length) in [(from_node_data, from_length), (to_node_data, to_length)] {
+            if overlap > length {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "overlap of {overlap} exceeds the length {length} of segment {}",
+                        node_data.name()
+                    ),
+                ));
+            }
+        }
+
+        // The alignment lies at the end of the oriented from segment and at the start of the oriented to segment.
+        // Positions refer to the forward strand, so the sides swap for reverse orientations.
+        let (from_begin, from_end) = if edge_data.from().is_forward() {
+            (from_length - overlap, from_length)
+        } else {
+            (0, overlap)
+        };
+        let (to_begin, to_end) = if edge_data.to().is_forward() {
+            (0, overlap)
+        } else {
+            (to_length - overlap, to_length)
+        };
+
+        let from_sign = if edge_data.from().is_forward() {
+            '+'
+        } else {
+            '-'
+        };
+        let to_sign = if edge_data.to().is_forward() {
+            '+'
+        } else {
+            '-'
+        };
+        let alignment = match edge_data.data().cigar() {
+            Some(cigar) => cigar,
+            None if edge_data.data().is_overlap_specified() => Cow::Owned(format!("{overlap}M")),
+            None => Cow::Borrowed("*"),
+        };
+
+        writeln!(
+            writer,
+            "E\t*\t{}{from_sign}\t{}{to_sign}\t{}\t{}\t{}\t{}\t{alignment}",
+            from_node_data.name(),
+            to_node_data.name(),
+            format_position(from_begin, from_length),
+            format_position(from_end, from_length),
+            format_position(to_begin, to_length),
+            format_position(to_end, to_length),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Formats a GFA2 position, marking the end of the segment with a `$` suffix.
+fn format_position(position: usize, segment_length: usize) -> String {
+    if position == segment_length {
+        format!("{position}$")
+    } else {
+        position.to_string()
+    }
+}
+
+/// Where an alignment lies on a segment in the orientation given by its reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AlignedSide {
+    Start,
+    End,
+    /// The alignment covers the whole segment.
+    Whole,
+    /// The alignment touches neither end of the segment.
+    Interior,
+}
+
+struct SegmentEnd<IndexType> {
+    node: NodeIndex<IndexType>,
+    forward: bool,
+    side: AlignedSide,
+    alignment_length: usize,
+}
+
+/// Parses a GFA2 segment reference like `s1+` together with the positions of the alignment on it.
+fn parse_segment_end<IndexType: GraphIndexInteger>(
+    node_name_to_node: &HashMap<String, (NodeIndex<IndexType>, usize)>,
+    reference: &str,
+    begin: &str,
+    end: &str,
+) -> Result<SegmentEnd<IndexType>, Gfa2ReadError> {
+    let (name, forward) = if let Some(name) = reference.strip_suffix('+') {
+        (name, true)
+    } else if let Some(name) = reference.strip_suffix('-') {
+        (name, false)
+    } else {
+        return Err(Gfa2ReadError::MissingOrientationInSegmentReference(
+            reference.to_string(),
+        ));
+    };
+    let (node, length) = node_name_to_node
+        .get(name)
+        .copied()
+        .ok_or_else(|| Gfa2ReadError::UnknownNodeName(name.to_string()))?;
+
+    let begin = parse_position(begin, length)?;
+    let end = parse_position(end, length)?;
+    let alignment_length = end
+        .checked_sub(begin)
+        .ok_or_else(|| Gfa2ReadError::InvalidPosition(format!("{begin}..{end}")))?;
+
+    // Positions always refer to the forward strand, so the sides swap for reverse references.
+    let side = match (begin == 0, end == length) {
+        (true, true) => AlignedSide::Whole,
+        (true, false) if forward => AlignedSide::Start,
+        (true, false) => AlignedSide::End,
+        (false, true) if forward => AlignedSide::End,
+        (false, true) => AlignedSide::Start,
+        (false, false) => AlignedSide::Interior,
+    };
+
+    Ok(SegmentEnd {
+        node,
+        forward,
+        side,
+        alignment_length,
+    })
+}
+
+/// Parses a GFA2 position, which may carry a `$` suffix to mark the end of the segment.
+fn parse_position(position: &str, segment_length: usize) -> Result<usize, Gfa2ReadError> {
+    let (value, is_end) = match position.strip_suffix('$') {
+        Some(value) => (value, true),
+        None => (position, false),
+    };
+    let value = value
+        .parse::<usize>()
+        .map_err(|_| Gfa2ReadError::InvalidPosition(position.to_string()))?;
+
+    if value > segment_length || (is_end && value != segment_length) {
+        Err(Gfa2ReadError::InvalidPosition(position.to_string()))
+    } else {
+        Ok(value)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge},
+    io::{
+        gfa1::{GfaNodeData, PlainGfaEdgeData, PlainGfaNodeData, read_gfa1},
+        gfa2::{Gfa2ReadError, read_gfa2, write_gfa2},
+    },
+    test_util::directed,
+};
+
+#[test]
+fn test_read_gfa2_matches_gfa1() {
+    let gfa1 = "H\tVN:Z:1.0\nS\tN0\tACGT\nS\tN1\t*\nS\tN2\tTTGCA\nL\tN0\t+\tN1\t+\t2M\nL\tN1\t+\tN2\t-\t1M\nL\tN2\t-\tN0\t-\t0M\nL\tN2\t+\tN2\t-\t3M\n";
+    let gfa2 = "H\tVN:Z:2.0\n\
+        S\tN0\t4\tACGT\n\
+        S\tN1\t6\t*\tLN:i:6\n\
+        S\tN2\t5\tTTGCA\n\
+        E\te1\tN0+\tN1+\t2\t4$\t0\t2\t2M\n\
+        E\t*\tN1+\tN2-\t5\t6$\t4\t5$\t1M\n\
+        E\t*\tN2-\tN0-\t0\t0\t4$\t4$\t0M\n\
+        E\t*\tN2+\tN2-\t2\t5$\t2\t5$\t3M\n\
+        E\t*\tN0+\tN2+\t1\t3\t0\t2\t2M\n";
+
+    let gfa1_graph = read_gfa1::<u16>(&mut gfa1.as_bytes()).unwrap();
+    let gfa2_graph = read_gfa2::<u16>(&mut gfa2.as_bytes()).unwrap();
+
+    assert_eq!(gfa2_graph.node_count(), 3);
+    assert_eq!(gfa2_graph.edge_count(), 4);
+    for node in gfa1_graph.iter_nodes() {
+        let gfa1_node = gfa1_graph.node_data(node);
+        let gfa2_node = gfa2_graph.node_data(node);
+        assert_eq!(gfa1_node.name(), gfa2_node.name());
+        assert_eq!(gfa1_node.sequence(), gfa2_node.sequence());
+    }
+    assert_eq!(gfa2_graph.node_data(1.into()).sequence_length(), Some(6));
+    for edge in gfa1_graph.iter_edges() {
+        let gfa1_edge = gfa1_graph.edge(edge);
+        let gfa2_edge = gfa2_graph.edge(edge);
+        assert_eq!(gfa1_edge.from(), gfa2_edge.from());
+        assert_eq!(gfa1_edge.to(), gfa2_edge.to());
+        assert_eq!(gfa1_edge.data(), gfa2_edge.data());
+    }
+}
+
+#[test]
+fn test_write_read_gfa2_triangle() {
+    let nodes = vec![
+        PlainGfaNodeData::new("N0", "ACG"),
+        PlainGfaNodeData::new_with_length("N1", 4),
+        PlainGfaNodeData::new("N2", "TTA"),
+    ];
+    let edges = vec![
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, true), 0),
+        BidirectedEdge::new_gfa(directed(1, true), directed(2, false), 1),
+        BidirectedEdge::new_gfa(directed(2, false), directed(0, true), 2),
+    ];
+    let expected_graph = BidirectedAdjacencyArray::<u16, _, _>::new(nodes.into(), edges.into());
+
+    let mut buffer = Vec::new();
+    write_gfa2(&expected_graph, &mut buffer).unwrap();
+    let actual_gfa = std::str::from_utf8(&buffer).unwrap().trim();
+    let actual_graph = read_gfa2::<u16>(&mut buffer.as_slice()).unwrap();
+
+    let expected_gfa = "H\tVN:Z:2.0\nS\tN0\t3\tACG\nS\tN1\t4\t*\nS\tN2\t3\tTTA\nE\t*\tN0+\tN1+\t3$\t3$\t0\t0\t0M\nE\t*\tN1+\tN2-\t3\t4$\t2\t3$\t1M\nE\t*\tN2-\tN0+\t0\t2\t0\t2\t2M";
+
+    expected_graph.expect_equal(&actual_graph);
+    assert_eq!(expected_gfa, actual_gfa);
+}
+
+#[test]
+fn test_write_read_gfa2_overlaps() {
+    let nodes = vec![
+        PlainGfaNodeData::new("N0", "ACGT"),
+        PlainGfaNodeData::new("N1", "GTTA"),
+        PlainGfaNodeData::new_with_length("N2", 5),
+    ];
+    let edges = vec![
+        BidirectedEdge::new_gfa(directed(0, true), directed(1, true), 2),
+        BidirectedEdge::new_gfa(directed(1, false), directed(2, true), 3),
+        BidirectedEdge::new_gfa(directed(2, true), directed(2, false), 4),
+        BidirectedEdge {
+            from: 0.into(),
+            from_forward: false,
+            to: 2.into(),
+            to_forward: false,
+            data: PlainGfaEdgeData::new_unspecified(),
+        },
+    ];
+    let expected_graph = BidirectedAdjacencyArray::<u16, _, _>::new(nodes.into(), edges.into());
+
+    let mut buffer = Vec::new();
+    write_gfa2(&expected_graph, &mut buffer).unwrap();
+    let actual_gfa = std::str::from_utf8(&buffer).unwrap();
+    assert!(actual_gfa.contains("E\t*\tN0+\tN1+\t2\t4$\t0\t2\t2M\n"));
+    assert!(actual_gfa.contains("E\t*\tN2+\tN2-\t1\t5$\t1\t5$\t4M\n"));
+    assert!(actual_gfa.contains("E\t*\tN0-\tN2-\t0\t0\t5$\t5$\t*\n"));
+
+    let actual_graph = read_gfa2::<u16>(&mut buffer.as_slice()).unwrap();
+    expected_graph.expect_equal(&actual_graph);
+
+    let mut rewritten = Vec::new();
+    write_gfa2(&actual_graph, &mut rewritten).unwrap();
+    assert_eq!(buffer, rewritten);
+}
+
+#[test]
+fn test_write_gfa2_overlap_exceeds_unknown_length() {
+    let nodes = vec![
+        PlainGfaNodeData::new("N0", "ACGT"),
+        PlainGfaNodeData::new_without_sequence("N1"),
+    ];
+    let edges = vec![BidirectedEdge::new_gfa(
+        directed(0, true),
+        directed(1, true),
+        2,
+    )];
+    let graph = BidirectedAdjacencyArray::<u16, _, _>::new(nodes.into(), edges.into());
+
+    let error = write_gfa2(&graph, &mut Vec::new()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn test_read_gfa2_errors() {
+    let read = |gfa: &str| {
+        read_gfa2::<u16>(&mut gfa.as_bytes())
+            .map(|_| ())
+            .unwrap_err()
+    };
+
+    assert!(matches!(
+        read("S\tN0\tx\t*\n"),
+        Gfa2ReadError::InvalidSegmentLength(length) if length == "x"
+    ));
+    assert!(matches!(
+        read("S\tN0\t4\tACGT\nE\t*\tN0+\tN0+\t0\n"),
+        Gfa2ReadError::ELineTooShort
+    ));
+    assert!(matches!(
+        read("S\tN0\t4\tACGT\nE\t*\tN0\tN0+\t2\t4$\t0\t2\t2M\n"),
+        Gfa2ReadError::MissingOrientationInSegmentReference(reference) if reference == "N0"
+    ));
+    assert!(matches!(
+        read("S\tN0\t4\tACGT\nE\t*\tN0+\tN0+\t2\t5\t0\t2\t2M\n"),
+        Gfa2ReadError::InvalidPosition(position) if position == "5"
+    ));
+}
@@ -0,0 +1,51 @@
+//! Helpers for working with DNA sequences.
+
+#[cfg(test)]
+mod tests;
+
+/// Returns the complement of a nucleotide.
+///
+/// Supports the IUPAC nucleotide codes in upper and lower case.
+/// `U` is complemented to `A`, and all other characters are returned unchanged.
+pub fn complement(nucleotide: char) -> char {
+    match nucleotide {
+        'A' => 'T',
+        'C' => 'G',
+        'G' => 'C',
+        'T' | 'U' => 'A',
+        'R' => 'Y',
+        'Y' => 'R',
+        'K' => 'M',
+        'M' => 'K',
+        'B' => 'V',
+        'V' => 'B',
+        'D' => 'H',
+        'H' => 'D',
+        'a' => 't',
+        'c' => 'g',
+        'g' => 'c',
+        't' | 'u' => 'a',
+        'r' => 'y',
+        'y' => 'r',
+        'k' => 'm',
+        'm' => 'k',
+        'b' => 'v',
+        'v' => 'b',
+        'd' => 'h',
+        'h' => 'd',
+        // S, W and N as well as unknown characters are their own complement.
+        other => other,
+    }
+}
+
+/// Iterates over the reverse complement of the given sequence without allocating.
+pub fn reverse_complement_iter(sequence: &str) -> impl DoubleEndedIterator<Item = char> + '_ {
+    sequence.chars().rev().map(complement)
+}
+
+/// Returns the reverse complement of the given sequence.
+///
+/// See [`complement`] for the supported characters.
+pub fn reverse_complement(sequence: &str) -> String {
+    reverse_complement_iter(sequence).collect()
+}
@@ -0,0 +1,23 @@
+use crate::io::sequence::{complement, reverse_complement, reverse_complement_iter};
+
+#[test]
+fn test_reverse_complement() {
+    assert_eq!(reverse_complement(""), "");
+    assert_eq!(reverse_complement("AACGTN"), "NACGTT");
+    assert_eq!(reverse_complement("aacgtn"), "nacgtt");
+    assert_eq!(reverse_complement("ACgt"), "acGT");
+    assert_eq!(reverse_complement("RYKMSWBDHVN"), "NBDHVWSKMRY");
+    assert_eq!(
+        reverse_complement_iter("ACCGT").collect::<String>(),
+        reverse_complement("ACCGT")
+    );
+}
+
+#[test]
+fn test_complement_is_involution() {
+    for nucleotide in "ACGTRYKMSWBDHVNacgtrykmswbdhvn".chars() {
+        assert_eq!(complement(complement(nucleotide)), nucleotide);
+    }
+    assert_eq!(complement('U'), 'A');
+    assert_eq!(complement('-'), '-');
+}
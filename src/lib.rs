@@ -1,8 +1,24 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+// The `tagged-vec` and `optional-numeric-index` dependencies link `std` themselves,
+// and the index types generated by `implement_generic_index!` refer to `std` paths.
+#[cfg(not(feature = "std"))]
+extern crate std;
+
+pub mod data;
 pub mod graph;
 pub mod index;
+#[cfg(feature = "std")]
 pub mod io;
 #[cfg(feature = "random")]
 pub mod random;
+#[cfg(test)]
+mod test_util;
 
 /// Implementations for comparing graphs.
 pub mod compare;
+
+/// Implementation of [`::arbitrary::Arbitrary`] for graphs.
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
@@ -27,7 +27,7 @@ impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
     pub fn generate_random_graph<Random: Rng>(
         num_nodes: usize,
         num_edges: usize,
-        mut node_data_generator: impl FnMut(NodeIndex<IndexType>, &mut Random) -> NodeData,
+        node_data_generator: impl FnMut(NodeIndex<IndexType>, &mut Random) -> NodeData,
         mut edge_data_generator: impl FnMut(&mut Random) -> EdgeData,
         rng: &mut Random,
     ) -> Result<
@@ -35,46 +35,135 @@ impl<IndexType: GraphIndexInteger, NodeData, EdgeData>
         RandomGraphError<IndexType, NodeData, EdgeData>,
     >
     where
-        EdgeData: Eq + Hash,
+        EdgeData: Eq + Hash + Clone,
+    {
+        Self::generate_random_graph_with_edge_key(
+            num_nodes,
+            num_edges,
+            node_data_generator,
+            |_, _, _, _, rng| edge_data_generator(rng),
+            rng,
+            BidirectedEdge::clone,
+        )
+    }
+
+    /// Generates a random bidirected graph like [`Self::generate_random_graph`], where the edge data depends on the endpoints.
+    ///
+    /// The edge data generator receives the fields `from`, `from_forward`, `to` and `to_forward` of the generated [`BidirectedEdge`].
+    /// This allows e.g. to bound the overlap of an edge by the sequence lengths of its endpoints.
+    pub fn generate_random_graph_with_endpoint_edge_data<Random: Rng>(
+        num_nodes: usize,
+        num_edges: usize,
+        node_data_generator: impl FnMut(NodeIndex<IndexType>, &mut Random) -> NodeData,
+        edge_data_generator: impl FnMut(
+            NodeIndex<IndexType>,
+            bool,
+            NodeIndex<IndexType>,
+            bool,
+            &mut Random,
+        ) -> EdgeData,
+        rng: &mut Random,
+    ) -> Result<
+        BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+        RandomGraphError<IndexType, NodeData, EdgeData>,
+    >
+    where
+        EdgeData: Eq + Hash + Clone,
     {
+        Self::generate_random_graph_with_edge_key(
+            num_nodes,
+            num_edges,
+            node_data_generator,
+            edge_data_generator,
+            rng,
+            BidirectedEdge::clone,
+        )
+    }
+
+    /// Generates a random bidirected graph with the specified number of nodes and edges, without parallel edges.
+    ///
+    /// Unlike [`Self::generate_random_graph`], two edges with the same endpoints and orientations
+    /// are considered duplicates even if their data differs, see [`BidirectedEdge::structural_eq`].
+    /// Hence, the edge data does not need to be hashable.
+    ///
+    /// If the edge generator repeatedly produces an edge that is already present in the graph,
+    /// then the generation is aborted with an error.
+    pub fn generate_random_graph_without_parallel_edges<Random: Rng>(
+        num_nodes: usize,
+        num_edges: usize,
+        node_data_generator: impl FnMut(NodeIndex<IndexType>, &mut Random) -> NodeData,
+        mut edge_data_generator: impl FnMut(&mut Random) -> EdgeData,
+        rng: &mut Random,
+    ) -> Result<
+        BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+        RandomGraphError<IndexType, NodeData, EdgeData>,
+    > {
+        Self::generate_random_graph_with_edge_key(
+            num_nodes,
+            num_edges,
+            node_data_generator,
+            |_, _, _, _, rng| edge_data_generator(rng),
+            rng,
+            |edge| (edge.from, edge.from_forward, edge.to, edge.to_forward),
+        )
+    }
+
+    /// Generates a random graph, where edges are considered duplicates if they have the same key.
+    fn generate_random_graph_with_edge_key<Random: Rng, EdgeKey: Eq + Hash>(
+        num_nodes: usize,
+        num_edges: usize,
+        mut node_data_generator: impl FnMut(NodeIndex<IndexType>, &mut Random) -> NodeData,
+        mut edge_data_generator: impl FnMut(
+            NodeIndex<IndexType>,
+            bool,
+            NodeIndex<IndexType>,
+            bool,
+            &mut Random,
+        ) -> EdgeData,
+        rng: &mut Random,
+        key_of: impl Fn(&BidirectedEdge<IndexType, EdgeData>) -> EdgeKey,
+    ) -> Result<
+        BidirectedAdjacencyArray<IndexType, NodeData, EdgeData>,
+        RandomGraphError<IndexType, NodeData, EdgeData>,
+    > {
         let mut nodes = TaggedVec::with_capacity(num_nodes);
         for node_index in 0..num_nodes {
             nodes.push(node_data_generator(NodeIndex::from_usize(node_index), rng));
         }
 
-        let mut edges = HashSet::new();
+        // The edges are collected in a vector such that their order only depends on the random generator.
+        // The set of their keys is only used to detect duplicates.
+        let mut edges = TaggedVec::new();
+        let mut edge_keys = HashSet::new();
         let mut stall_counter = 0;
 
         while edges.len() < num_edges {
-            let from = rng.random_range(0..num_nodes);
-            let to = rng.random_range(0..num_nodes);
+            let from = NodeIndex::from_usize(rng.random_range(0..num_nodes));
+            let to = NodeIndex::from_usize(rng.random_range(0..num_nodes));
             let from_forward = rng.random_bool(0.5);
             let to_forward = rng.random_bool(0.5);
 
             let edge = BidirectedEdge {
-                from: NodeIndex::from_usize(from),
+                from,
                 from_forward,
-                to: NodeIndex::from_usize(to),
+                to,
                 to_forward,
-                data: edge_data_generator(rng),
+                data: edge_data_generator(from, from_forward, to, to_forward, rng),
             };
 
-            let was_modified = edges.insert(edge);
-            if was_modified {
+            if edge_keys.insert(key_of(&edge)) {
+                edges.push(edge);
                 stall_counter = 0;
             } else {
                 stall_counter += 1;
                 if stall_counter > 10 {
                     return Err(RandomGraphError::RandomGenerationStalled(
-                        BidirectedAdjacencyArray::new(nodes, edges.into_iter().collect()),
+                        BidirectedAdjacencyArray::new(nodes, edges),
                     ));
                 }
             }
         }
 
-        Ok(BidirectedAdjacencyArray::new(
-            nodes,
-            edges.into_iter().collect(),
-        ))
+        Ok(BidirectedAdjacencyArray::new(nodes, edges))
     }
 }
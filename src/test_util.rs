@@ -0,0 +1,53 @@
+//! Helpers and example graphs shared by the unit tests.
+
+use crate::{
+    graph::{BidirectedAdjacencyArray, BidirectedEdge},
+    index::{DirectedNodeIndex, GraphIndexInteger},
+    io::gfa1::{PlainGfaEdgeData, PlainGfaNodeData},
+};
+
+pub(crate) fn directed<IndexType: GraphIndexInteger>(
+    node: usize,
+    forward: bool,
+) -> DirectedNodeIndex<IndexType> {
+    DirectedNodeIndex::from_bidirected(node.into(), forward)
+}
+
+/// Builds a graph from its node data and its edges given as `(from, to, data)`.
+pub(crate) fn graph<NodeData, EdgeData>(
+    nodes: Vec<NodeData>,
+    edges: Vec<(DirectedNodeIndex<u8>, DirectedNodeIndex<u8>, EdgeData)>,
+) -> BidirectedAdjacencyArray<u8, NodeData, EdgeData> {
+    BidirectedAdjacencyArray::new(
+        nodes.into(),
+        edges
+            .into_iter()
+            .map(|(from, to, data)| BidirectedEdge::new(from, to, data))
+            .collect(),
+    )
+}
+
+/// Builds a graph without node and edge data from its node count and its edges given as `(from, to)`.
+pub(crate) fn unit_graph(
+    node_count: usize,
+    edges: &[(DirectedNodeIndex<u8>, DirectedNodeIndex<u8>)],
+) -> BidirectedAdjacencyArray<u8, (), ()> {
+    graph(
+        vec![(); node_count],
+        edges.iter().map(|&(from, to)| (from, to, ())).collect(),
+    )
+}
+
+/// Builds a GFA graph from its segments and its links given as `(from, to, overlap)`.
+pub(crate) fn gfa_graph(
+    nodes: Vec<PlainGfaNodeData>,
+    edges: &[(DirectedNodeIndex<u16>, DirectedNodeIndex<u16>, u16)],
+) -> BidirectedAdjacencyArray<u16, PlainGfaNodeData, PlainGfaEdgeData> {
+    BidirectedAdjacencyArray::new(
+        nodes.into(),
+        edges
+            .iter()
+            .map(|&(from, to, overlap)| BidirectedEdge::new_gfa(from, to, overlap))
+            .collect(),
+    )
+}